@@ -0,0 +1,37 @@
+//! Compares looking up days in a prebuilt `CalendarYear` with calling `day_kind`.
+
+#![allow(deprecated)]
+
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use holidays_se::{day_kind, CalendarYear};
+
+fn dates_2024() -> Vec<NaiveDate> {
+    (1..=366)
+        .map(|ordinal| NaiveDate::from_yo(2024, ordinal))
+        .collect()
+}
+
+fn lookups(c: &mut Criterion) {
+    let dates = dates_2024();
+    let year = CalendarYear::new(2024);
+
+    c.bench_function("CalendarYear::kind_of", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(year.kind_of(black_box(*date)));
+            }
+        })
+    });
+
+    c.bench_function("day_kind", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(day_kind(black_box(date)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, lookups);
+criterion_main!(benches);
@@ -0,0 +1,612 @@
+use crate::{dates_in, day_kind, holiday_at, holidays, DayKind, Holiday};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use chrono_tz::{Europe::Stockholm, Tz};
+use std::{cell::RefCell, collections::HashSet, error, fmt};
+
+/// Configuration shared by calendars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarConfig {
+    pub timezone: Tz,
+}
+
+impl CalendarConfig {
+    /// The Swedish standard, Europe/Stockholm.
+    pub fn stockholm() -> Self {
+        Self {
+            timezone: Stockholm,
+        }
+    }
+}
+
+/// Same as `CalendarConfig::stockholm`.
+impl Default for CalendarConfig {
+    fn default() -> Self {
+        Self::stockholm()
+    }
+}
+
+/// How a `BusinessCalendar` moves holidays falling on a Saturday or Sunday, if at all.
+///
+/// Sweden never moves holidays, but payroll systems shared with other countries often do.
+/// When the weekday a holiday is moved to is already taken by another holiday, it is moved
+/// further ahead to the next free weekday. Holidays always on a weekend, like Påskdagen,
+/// are moved as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObservedPolicy {
+    /// Holidays are observed on their actual date, the Swedish practice.
+    None,
+    /// Holidays on a weekend are observed on the Monday after.
+    NextWeekday,
+    /// Holidays on a Saturday are observed on the Friday before, and on a Sunday
+    /// the Monday after.
+    NearestWeekday,
+}
+
+/// Same as `ObservedPolicy::None`.
+impl Default for ObservedPolicy {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+type ObservedDates = (Vec<Holiday>, ObservedPolicy, i32, Vec<NaiveDate>);
+
+thread_local! {
+    static OBSERVED_CACHE: RefCell<Option<ObservedDates>> = const { RefCell::new(None) };
+}
+
+/// A calendar of days off, classifying dates the same way as `day_kind`
+/// but with a configurable set of holidays.
+///
+/// Built using `HolidayCalendarBuilder`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    holidays: Vec<Holiday>,
+    // Sorted.
+    extra_days: Vec<NaiveDate>,
+    observed: ObservedPolicy,
+}
+
+impl BusinessCalendar {
+    /// Returns true if date is one of the calendars holidays or extra days off.
+    /// Sundays are not included, unless explicitly added.
+    /// Holidays only count in the years they're in effect, see `RuleSet`.
+    ///
+    /// Holidays are days off on the date they're observed, see `ObservedPolicy`.
+    pub fn is_day_off(&self, date: NaiveDate) -> bool {
+        if self.extra_days.binary_search(&date).is_ok() {
+            return true;
+        }
+
+        let year = date.year();
+        if self.observed == ObservedPolicy::None {
+            return self
+                .holidays
+                .iter()
+                .any(|h| h.is_holiday_in(year) && h.date_in(year) == date);
+        }
+
+        self.with_observed_dates(year, |dates| dates.binary_search(&date).is_ok())
+    }
+
+    /// Calls f with the sorted dates of the holidays observed within year.
+    /// The dates of the most recently used calendar and year are cached per thread.
+    fn with_observed_dates<F, R>(&self, year: i32, f: F) -> R
+    where
+        F: FnOnce(&[NaiveDate]) -> R,
+    {
+        OBSERVED_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            let key = (self.holidays.as_slice(), self.observed, year);
+            match &*cache {
+                Some((holidays, observed, cached_year, _))
+                    if (holidays.as_slice(), *observed, *cached_year) == key => {}
+                _ => {
+                    let dates = self
+                        .holidays_in_year(year)
+                        .into_iter()
+                        .map(|(d, _)| d)
+                        .collect();
+                    *cache = Some((self.holidays.clone(), self.observed, year, dates));
+                }
+            }
+
+            let (.., dates) = cache.as_ref().expect("Observed cache was just populated");
+            f(dates)
+        })
+    }
+
+    /// Returns the holidays of the calendar observed within year, in chronological order.
+    ///
+    /// Depending on the `ObservedPolicy` a holiday may be observed in a different year
+    /// than it falls on, like Nyårsafton on a Saturday moved to the Monday after.
+    pub fn holidays_in_year(&self, year: i32) -> Vec<(NaiveDate, Holiday)> {
+        let mut holidays = self.observed_holidays(year);
+        holidays.retain(|(d, _)| d.year() == year);
+        holidays
+    }
+
+    /// The holidays from the year before through the year after year, moved according to the
+    /// policy and sorted by the date they're observed.
+    fn observed_holidays(&self, year: i32) -> Vec<(NaiveDate, Holiday)> {
+        let mut actual = (year - 1..=year + 1)
+            .flat_map(|y| {
+                self.holidays
+                    .iter()
+                    .filter(move |h| h.is_holiday_in(y))
+                    .map(move |h| (h.date_in(y), *h))
+            })
+            .collect::<Vec<_>>();
+        actual.sort_by_key(|(d, _)| *d);
+
+        if self.observed == ObservedPolicy::None {
+            return actual;
+        }
+
+        let is_weekend = |d: NaiveDate| d.weekday() == Weekday::Sat || d.weekday() == Weekday::Sun;
+        let mut taken = actual
+            .iter()
+            .map(|(d, _)| *d)
+            .filter(|d| !is_weekend(*d))
+            .collect::<HashSet<_>>();
+
+        let mut observed = actual
+            .into_iter()
+            .map(|(date, holiday)| {
+                let mut moved = match (self.observed, date.weekday()) {
+                    (ObservedPolicy::NearestWeekday, Weekday::Sat) => date.pred(),
+                    (_, Weekday::Sat) => date + Duration::days(2),
+                    (_, Weekday::Sun) => date.succ(),
+                    _ => return (date, holiday),
+                };
+
+                while is_weekend(moved) || taken.contains(&moved) {
+                    moved = moved.succ();
+                }
+                taken.insert(moved);
+                (moved, holiday)
+            })
+            .collect::<Vec<_>>();
+        observed.sort_by_key(|(d, _)| *d);
+        observed
+    }
+
+    /// Returns the `DayKind` of date, by the days off of this calendar.
+    ///
+    /// Sundays and days off are `DayKind::Holiday`, Saturdays and the days before them
+    /// `DayKind::DayBeforeHoliday`. The settings of `DayKindPolicy`, like weekends
+    /// and half days, don't apply.
+    pub fn day_kind(&self, date: NaiveDate) -> DayKind {
+        let weekday = date.weekday();
+        if weekday == Weekday::Sun || self.is_day_off(date) {
+            DayKind::Holiday
+        } else if weekday == Weekday::Sat || self.is_day_off(date.succ()) {
+            DayKind::DayBeforeHoliday
+        } else {
+            DayKind::Weekday
+        }
+    }
+}
+
+/// All the Swedish holidays, without any extra days off.
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        HolidayCalendarBuilder::new()
+            .swedish_holidays()
+            .build()
+            .expect("Nothing is removed from the calendar")
+    }
+}
+
+/// A single day of a `CalendarYear`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DayEntry {
+    pub date: NaiveDate,
+    pub kind: DayKind,
+    /// The named holiday of the day, if any.
+    pub holiday: Option<Holiday>,
+}
+
+/// Every day of a year, classified up front.
+///
+/// Meant for many repeated lookups within the same year, each lookup is
+/// a plain index by day of year.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarYear {
+    year: i32,
+    // One entry per day, indexed by ordinal0.
+    days: Vec<DayEntry>,
+}
+
+impl CalendarYear {
+    pub fn new(year: i32) -> Self {
+        let start = NaiveDate::from_ymd(year, 1, 1);
+        let end = NaiveDate::from_ymd(year + 1, 1, 1);
+        let days = dates_in(start..end)
+            .map(|date| DayEntry {
+                date,
+                kind: day_kind(&date),
+                holiday: holiday_at(date),
+            })
+            .collect();
+
+        Self { year, days }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the `DayKind` of date, or `None` if date is not in this year.
+    pub fn kind_of(&self, date: NaiveDate) -> Option<DayKind> {
+        self.entry(date).map(|e| e.kind)
+    }
+
+    /// Returns the named holiday on date.
+    /// `None` if there is none or date is not in this year.
+    pub fn holiday_at(&self, date: NaiveDate) -> Option<Holiday> {
+        self.entry(date).and_then(|e| e.holiday)
+    }
+
+    /// Iterates every day of the year, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &DayEntry> {
+        self.days.iter()
+    }
+
+    fn entry(&self, date: NaiveDate) -> Option<&DayEntry> {
+        if date.year() != self.year {
+            return None;
+        }
+
+        self.days.get(date.ordinal0() as usize)
+    }
+}
+
+/// Fluent builder of `BusinessCalendar`s.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{DayKind, Holiday, HolidayCalendarBuilder};
+///
+/// let calendar = HolidayCalendarBuilder::new()
+///     .swedish_holidays()
+///     .add_extra_day(NaiveDate::from_ymd(2024, 5, 10))
+///     .remove_holiday(Holiday::Nationaldagen)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(DayKind::Holiday, calendar.day_kind(NaiveDate::from_ymd(2024, 5, 10)));
+/// assert_eq!(DayKind::Weekday, calendar.day_kind(NaiveDate::from_ymd(2024, 6, 6)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HolidayCalendarBuilder {
+    holidays: Vec<Holiday>,
+    extra_days: Vec<NaiveDate>,
+    removed: Vec<Holiday>,
+    observed: ObservedPolicy,
+}
+
+impl HolidayCalendarBuilder {
+    /// Creates a builder without any holidays at all.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the holidays to all the Swedish holidays, replacing any set before.
+    pub fn swedish_holidays(mut self) -> Self {
+        self.holidays = holidays().collect();
+        self
+    }
+
+    /// Sets the holidays to the Swedish red days only, replacing any set before.
+    /// Leaves out the de facto holidays Julafton, Midsommarafton and Nyårsafton.
+    /// See `Holiday::is_de_facto`.
+    pub fn swedish_red_days(mut self) -> Self {
+        self.holidays = holidays().filter(|h| h.is_red_day()).collect();
+        self
+    }
+
+    /// Adds a day off, classified as a holiday.
+    pub fn add_extra_day(mut self, date: NaiveDate) -> Self {
+        self.extra_days.push(date);
+        self
+    }
+
+    /// Same as `add_extra_day`, for every date of dates.
+    pub fn add_extra_days<I>(mut self, dates: I) -> Self
+    where
+        I: IntoIterator<Item = NaiveDate>,
+    {
+        self.extra_days.extend(dates);
+        self
+    }
+
+    /// Excludes a holiday from the calendar.
+    /// The holiday must have been added, otherwise `build` fails. Removing it more than
+    /// once is the same as removing it once.
+    pub fn remove_holiday(mut self, holiday: Holiday) -> Self {
+        self.removed.push(holiday);
+        self
+    }
+
+    /// Sets how holidays on weekends are moved, `ObservedPolicy::None` by default.
+    pub fn observed_policy(mut self, policy: ObservedPolicy) -> Self {
+        self.observed = policy;
+        self
+    }
+
+    pub fn build(self) -> Result<BusinessCalendar, BuilderError> {
+        let Self {
+            mut holidays,
+            mut extra_days,
+            removed,
+            observed,
+        } = self;
+
+        if let Some(holiday) = removed.iter().find(|h| !holidays.contains(h)) {
+            return Err(BuilderError::UnknownHoliday(*holiday));
+        }
+        holidays.retain(|h| !removed.contains(h));
+
+        extra_days.sort();
+        extra_days.dedup();
+
+        Ok(BusinessCalendar {
+            holidays,
+            extra_days,
+            observed,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderError {
+    /// Tried to remove a holiday that isn't in the calendar.
+    UnknownHoliday(Holiday),
+}
+
+impl fmt::Display for BuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownHoliday(holiday) => {
+                write!(f, "{:?} is not a holiday in the calendar", holiday)
+            }
+        }
+    }
+}
+
+impl error::Error for BuilderError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_of_red_days() {
+        let calendar = HolidayCalendarBuilder::new()
+            .swedish_red_days()
+            .build()
+            .unwrap();
+
+        let christmas_eve = NaiveDate::from_ymd(2024, 12, 24);
+        assert!(!calendar.is_day_off(christmas_eve));
+        assert_eq!(DayKind::DayBeforeHoliday, calendar.day_kind(christmas_eve));
+        assert_eq!(
+            DayKind::Holiday,
+            calendar.day_kind(NaiveDate::from_ymd(2024, 12, 25))
+        );
+    }
+
+    #[test]
+    fn test_calendar_without_national_day() {
+        let calendar = HolidayCalendarBuilder::new()
+            .swedish_holidays()
+            .remove_holiday(Holiday::Nationaldagen)
+            .build()
+            .unwrap();
+
+        // A Thursday.
+        let national_day = NaiveDate::from_ymd(2024, 6, 6);
+        assert_eq!(DayKind::Weekday, calendar.day_kind(national_day));
+        assert_eq!(DayKind::Weekday, calendar.day_kind(national_day.pred()));
+
+        // Everything else is still there.
+        assert_eq!(
+            DayKind::Holiday,
+            calendar.day_kind(NaiveDate::from_ymd(2024, 6, 22))
+        );
+        assert_eq!(
+            DayKind::DayBeforeHoliday,
+            calendar.day_kind(NaiveDate::from_ymd(2024, 12, 23))
+        );
+    }
+
+    #[test]
+    fn test_calendar_agrees_with_day_kind() {
+        let calendar = HolidayCalendarBuilder::new()
+            .swedish_holidays()
+            .build()
+            .unwrap();
+
+        let start = NaiveDate::from_ymd(2020, 1, 1);
+        let end = NaiveDate::from_ymd(2021, 1, 1);
+        for date in crate::dates_in(start..end) {
+            assert_eq!(crate::day_kind(&date), calendar.day_kind(date), "{}", date);
+        }
+    }
+
+    #[test]
+    fn test_extra_days() {
+        // Friday after Kristi himmelsfärdsdag.
+        let friday = NaiveDate::from_ymd(2024, 5, 10);
+        let calendar = HolidayCalendarBuilder::new()
+            .swedish_holidays()
+            .add_extra_days(vec![friday, friday])
+            .build()
+            .unwrap();
+
+        assert_eq!(DayKind::Holiday, calendar.day_kind(friday));
+        assert_eq!(
+            DayKind::Weekday,
+            calendar.day_kind(NaiveDate::from_ymd(2024, 5, 13))
+        );
+    }
+
+    #[test]
+    fn test_observed_dates_per_calendar() {
+        let observed = |builder: HolidayCalendarBuilder| {
+            builder
+                .observed_policy(ObservedPolicy::NextWeekday)
+                .build()
+                .unwrap()
+        };
+        let all = observed(HolidayCalendarBuilder::new().swedish_holidays());
+        let without_national_day = observed(
+            HolidayCalendarBuilder::new()
+                .swedish_holidays()
+                .remove_holiday(Holiday::Nationaldagen),
+        );
+
+        // Nationaldagen 2020 is a Saturday, observed on the Monday after.
+        let monday = NaiveDate::from_ymd(2020, 6, 8);
+        for _ in 0..2 {
+            assert!(all.is_day_off(monday));
+            assert!(!without_national_day.is_day_off(monday));
+        }
+    }
+
+    #[test]
+    fn test_observed_next_weekday() {
+        let d = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        let calendar = HolidayCalendarBuilder::new()
+            .swedish_holidays()
+            .observed_policy(ObservedPolicy::NextWeekday)
+            .build()
+            .unwrap();
+
+        // Juldagen on a Saturday and Annandag jul on a Sunday, queued up after each other.
+        let christmas = calendar
+            .holidays_in_year(2021)
+            .into_iter()
+            .filter(|(d, _)| d.month() == 12)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (d(2021, 12, 24), Holiday::Julafton),
+                (d(2021, 12, 27), Holiday::Juldagen),
+                (d(2021, 12, 28), Holiday::AnnandagJul),
+                (d(2021, 12, 31), Holiday::Nyarsafton),
+            ],
+            christmas
+        );
+        assert_eq!(DayKind::Holiday, calendar.day_kind(d(2021, 12, 28)));
+        assert_eq!(
+            DayKind::DayBeforeHoliday,
+            calendar.day_kind(d(2021, 12, 30))
+        );
+
+        // Nyårsafton on a Saturday is observed in the next year, after Nyårsdagen.
+        assert!(!calendar
+            .holidays_in_year(2022)
+            .iter()
+            .any(|(_, h)| *h == Holiday::Nyarsafton));
+        assert_eq!(
+            vec![
+                (d(2023, 1, 2), Holiday::Nyarsafton),
+                (d(2023, 1, 3), Holiday::Nyarsdagen),
+            ],
+            calendar.holidays_in_year(2023)[..2].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_observed_nearest_weekday() {
+        let d = |m, d| NaiveDate::from_ymd(2020, m, d);
+        let calendar = HolidayCalendarBuilder::new()
+            .swedish_holidays()
+            .observed_policy(ObservedPolicy::NearestWeekday)
+            .build()
+            .unwrap();
+
+        let holidays = calendar.holidays_in_year(2020);
+        assert_eq!(16, holidays.len());
+        assert!(holidays.contains(&(d(6, 5), Holiday::Nationaldagen)));
+        // Midsommarafton already takes the Friday.
+        assert!(holidays.contains(&(d(6, 22), Holiday::Midsommardagen)));
+        // Påskdagen is moved past Annandag påsk.
+        assert!(holidays.contains(&(d(4, 14), Holiday::Paskdagen)));
+
+        for (date, _) in holidays {
+            assert!(date.weekday() != Weekday::Sat && date.weekday() != Weekday::Sun);
+        }
+        assert_eq!(DayKind::DayBeforeHoliday, calendar.day_kind(d(6, 4)));
+    }
+
+    #[test]
+    fn test_calendar_year_agrees_with_day_kind() {
+        let year = CalendarYear::new(2020);
+
+        assert_eq!(366, year.iter().count());
+        for entry in year.iter() {
+            assert_eq!(crate::day_kind(&entry.date), entry.kind, "{}", entry.date);
+            assert_eq!(Some(entry.kind), year.kind_of(entry.date));
+            assert_eq!(crate::holiday_at(entry.date), year.holiday_at(entry.date));
+        }
+
+        assert_eq!(365, CalendarYear::new(2021).iter().count());
+    }
+
+    #[test]
+    fn test_calendar_year_lookups() {
+        let year = CalendarYear::new(2024);
+
+        assert_eq!(
+            Some(Holiday::Nationaldagen),
+            year.holiday_at(NaiveDate::from_ymd(2024, 6, 6))
+        );
+        assert_eq!(
+            Some(DayKind::DayBeforeHoliday),
+            year.kind_of(NaiveDate::from_ymd(2024, 6, 5))
+        );
+        // An ordinary Sunday.
+        assert_eq!(None, year.holiday_at(NaiveDate::from_ymd(2024, 6, 9)));
+
+        assert_eq!(None, year.kind_of(NaiveDate::from_ymd(2023, 12, 31)));
+        assert_eq!(None, year.kind_of(NaiveDate::from_ymd(2025, 1, 1)));
+        assert_eq!(None, year.holiday_at(NaiveDate::from_ymd(2025, 1, 1)));
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(CalendarConfig::stockholm(), CalendarConfig::default());
+        assert_eq!(Stockholm, CalendarConfig::default().timezone);
+
+        assert_eq!(
+            HolidayCalendarBuilder::new()
+                .swedish_holidays()
+                .build()
+                .unwrap(),
+            BusinessCalendar::default()
+        );
+    }
+
+    #[test]
+    fn test_removing_unknown_holiday() {
+        assert_eq!(
+            Err(BuilderError::UnknownHoliday(Holiday::Nationaldagen)),
+            HolidayCalendarBuilder::new()
+                .remove_holiday(Holiday::Nationaldagen)
+                .build()
+        );
+
+        let calendar = HolidayCalendarBuilder::new()
+            .swedish_holidays()
+            .remove_holiday(Holiday::Nationaldagen)
+            .remove_holiday(Holiday::Nationaldagen)
+            .build()
+            .unwrap();
+        assert_eq!(
+            DayKind::Weekday,
+            calendar.day_kind(NaiveDate::from_ymd(2024, 6, 6))
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::DayKind;
+
+/// Returns the conventional color of kind in Swedish printed calendars, as RGB.
+/// Red for holidays, light red for weekends, gold for half days, orange for days before
+/// holidays and black for weekdays.
+pub fn day_kind_color(kind: DayKind) -> (u8, u8, u8) {
+    match kind {
+        DayKind::Holiday => (220, 50, 50),
+        DayKind::Weekend => (240, 128, 128),
+        DayKind::HalfDay => (255, 215, 0),
+        DayKind::DayBeforeHoliday => (255, 165, 0),
+        DayKind::Weekday => (0, 0, 0),
+    }
+}
+
+/// Same as `day_kind_color`, as an HTML hex color, e.g. "#DC3232".
+#[cfg(feature = "css-colors")]
+pub fn day_kind_hex_color(kind: DayKind) -> &'static str {
+    match kind {
+        DayKind::Holiday => "#DC3232",
+        DayKind::Weekend => "#F08080",
+        DayKind::HalfDay => "#FFD700",
+        DayKind::DayBeforeHoliday => "#FFA500",
+        DayKind::Weekday => "#000000",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KINDS: [DayKind; 5] = [
+        DayKind::Weekday,
+        DayKind::DayBeforeHoliday,
+        DayKind::HalfDay,
+        DayKind::Weekend,
+        DayKind::Holiday,
+    ];
+
+    #[test]
+    fn test_colors_are_distinct() {
+        for (i, a) in KINDS.iter().enumerate() {
+            for b in &KINDS[i + 1..] {
+                assert_ne!(day_kind_color(*a), day_kind_color(*b));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "css-colors")]
+    fn test_hex_colors() {
+        for kind in KINDS.iter().copied() {
+            let hex = day_kind_hex_color(kind);
+            assert_eq!(7, hex.len());
+            assert!(hex.starts_with('#'));
+            assert!(hex[1..].chars().all(|c| c.is_ascii_hexdigit()));
+
+            let (r, g, b) = day_kind_color(kind);
+            assert_eq!(format!("#{:02X}{:02X}{:02X}", r, g, b), hex);
+        }
+    }
+}
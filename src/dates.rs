@@ -0,0 +1,86 @@
+//! Fixed date holidays and eves.
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use holidays_se::dates;
+//!
+//! assert_eq!(NaiveDate::from_ymd(2024, 12, 24), dates::christmas_eve(2024));
+//! assert_eq!(NaiveDate::from_ymd(2024, 6, 6), dates::national_day(2024));
+//! ```
+
+use chrono::NaiveDate;
+
+/// Julafton, 24th of December.
+pub fn christmas_eve(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 12, 24)
+}
+
+/// Juldagen, 25th of December.
+pub fn christmas_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 12, 25)
+}
+
+/// Annandag jul, 26th of December.
+pub fn boxing_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 12, 26)
+}
+
+/// Nyårsafton, 31st of December.
+pub fn new_years_eve(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 12, 31)
+}
+
+/// Nyårsdagen, 1st of January.
+pub fn new_years_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 1, 1)
+}
+
+/// Första maj, 1st of May.
+pub fn labor_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 5, 1)
+}
+
+/// Nationaldagen, 6th of June.
+pub fn national_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 6, 6)
+}
+
+/// Trettondedag jul, 6th of January.
+pub fn epiphany(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd(year, 1, 6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Holiday;
+    use chrono::Datelike;
+
+    type FixedDate = fn(i32) -> NaiveDate;
+
+    #[test]
+    fn test_fixed_dates_2024() {
+        let cases: [(FixedDate, u32, u32); 8] = [
+            (christmas_eve, 12, 24),
+            (christmas_day, 12, 25),
+            (boxing_day, 12, 26),
+            (new_years_eve, 12, 31),
+            (new_years_day, 1, 1),
+            (labor_day, 5, 1),
+            (national_day, 6, 6),
+            (epiphany, 1, 6),
+        ];
+
+        for (f, month, day) in cases {
+            let date = f(2024);
+            assert_eq!(2024, date.year());
+            assert_eq!((month, day), (date.month(), date.day()));
+        }
+    }
+
+    #[test]
+    fn test_fixed_dates_match_holidays() {
+        assert_eq!(Holiday::Julafton.date_in(2024), christmas_eve(2024));
+        assert_eq!(Holiday::TrettondedagJul.date_in(2024), epiphany(2024));
+    }
+}
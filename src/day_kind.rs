@@ -1,83 +1,400 @@
-use chrono::{DateTime, Datelike, Duration, Weekday};
-use chrono_tz::Tz;
-use std::{iter, ops::Range};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::{holiday_at, is_klamdag_recommended, DayKindPolicy, Holiday, Reason};
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Weekday,
+};
+use chrono_tz::{Europe::Stockholm, Tz};
+use std::{
+    cmp,
+    convert::TryFrom,
+    error, fmt, iter,
+    ops::{BitAnd, BitOr, Range},
+    str::FromStr,
+};
+
+/// Ordered from ordinary working days to holidays.
+///
+/// `day_kind` only ever returns `Weekday`, `DayBeforeHoliday` and `Holiday`, every Sunday
+/// being a holiday. The other kinds are opt in through a `DayKindPolicy`, so matching on
+/// the three is enough for code using the default classification. New kinds may be added,
+/// so matches need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub enum DayKind {
     Weekday,
     DayBeforeHoliday,
+    /// A working day customarily ending at lunch, see `DayKindPolicy::with_half_days`.
+    HalfDay,
+    /// An ordinary Saturday or Sunday, see `DayKindPolicy::with_weekends`.
+    Weekend,
     Holiday,
 }
 
 impl DayKind {
     /// Returns the next occurence of self.
     /// If dt occurs on self, dt is returned
+    ///
+    /// Panics if self doesn't occur within a year, like `DayKind::Weekend` which `day_kind`
+    /// never returns. See `next_start_within` for a variant returning None instead.
     pub fn next_start(&self, dt: &DateTime<Tz>) -> DateTime<Tz> {
-        SliceIterator::from_dt(*dt)
-            .find(|slice| slice.kind == *self)
+        self.next_start_with(dt, DayKindPolicy::swedish())
+    }
+
+    /// Same as `next_start`, classifying days by policy.
+    pub fn next_start_with(&self, dt: &DateTime<Tz>, policy: DayKindPolicy) -> DateTime<Tz> {
+        self.find_start(dt, Duration::days(366), policy)
+            .unwrap_or_else(|| panic!("{:?} does not occur within a year of {}", self, dt))
+    }
+
+    /// Same as `next_start`, but only searching up until within after dt.
+    /// Returns None if self doesn't start before then.
+    ///
+    /// The search never goes past the last representable date, however large within is.
+    /// ```
+    /// use chrono::{Duration, TimeZone};
+    /// use chrono_tz::Europe::Stockholm;
+    /// use holidays_se::DayKind;
+    ///
+    /// // A Wednesday.
+    /// let dt = Stockholm.ymd(2020, 10, 21).and_hms(13, 37, 0);
+    /// assert_eq!(None, DayKind::Holiday.next_start_within(&dt, Duration::days(2)));
+    /// assert_eq!(
+    ///     Some(Stockholm.ymd(2020, 10, 25).and_hms(0, 0, 0)),
+    ///     DayKind::Holiday.next_start_within(&dt, Duration::days(7))
+    /// );
+    /// ```
+    pub fn next_start_within(&self, dt: &DateTime<Tz>, within: Duration) -> Option<DateTime<Tz>> {
+        self.find_start(dt, within, DayKindPolicy::swedish())
+    }
+
+    /// Returns the start of the nth block of self from dt, counting from 1.
+    /// `nth_next_start(dt, 1)` is the same as `next_start`, including dt itself if it occurs
+    /// on self.
+    ///
+    /// Returns None for n = 0, or if there aren't n blocks of self within n years.
+    /// ```
+    /// use chrono::TimeZone;
+    /// use chrono_tz::Europe::Stockholm;
+    /// use holidays_se::DayKind;
+    ///
+    /// // A Wednesday, the holidays are the next four Sundays.
+    /// let dt = Stockholm.ymd(2020, 10, 21).and_hms(13, 37, 0);
+    /// assert_eq!(
+    ///     Some(Stockholm.ymd(2020, 11, 15).and_hms(0, 0, 0)),
+    ///     DayKind::Holiday.nth_next_start(&dt, 4)
+    /// );
+    /// ```
+    pub fn nth_next_start(&self, dt: &DateTime<Tz>, n: usize) -> Option<DateTime<Tz>> {
+        let index = n.checked_sub(1)?;
+        let days = i64::try_from(n).unwrap_or(i64::MAX).saturating_mul(366);
+        let within = Duration::try_days(days).unwrap_or_else(Duration::max_value);
+        slice_on_day_kind(*dt..search_end(dt, within))
+            .filter(|slice| slice.matches_kind(*self))
+            .nth(index)
             .map(|slice| slice.range.start)
+    }
+
+    fn find_start(
+        &self,
+        dt: &DateTime<Tz>,
+        within: Duration,
+        policy: DayKindPolicy,
+    ) -> Option<DateTime<Tz>> {
+        slice_on_day_kind_with(*dt..search_end(dt, within), policy)
+            .find(|slice| slice.matches_kind(*self))
+            .map(|slice| slice.range.start)
+    }
+
+    /// Returns the start of the most recent block of self at or before dt, that is midnight
+    /// of its first day. If dt occurs on self, the start of the block dt is within is returned.
+    ///
+    /// Panics if self doesn't occur within a year before dt.
+    pub fn prev_start(&self, dt: &DateTime<Tz>) -> DateTime<Tz> {
+        let mut day = dt.naive_local().date();
+        let limit = day - Duration::days(366);
+        while day.day_kind() != *self {
+            day = day.pred();
+            if day < limit {
+                panic!("{:?} does not occur within a year before {}", self, dt);
+            }
+        }
+
+        while day.pred().day_kind() == *self {
+            day = day.pred();
+        }
+        dt.timezone()
+            .from_local_date(&day)
             .unwrap()
+            .and_hms(0, 0, 0)
+    }
+
+    /// Returns the whole block of days of the same kind as dt that dt is within,
+    /// like all of the christmas holidays.
+    /// ```
+    /// use chrono::TimeZone;
+    /// use chrono_tz::Europe::Stockholm;
+    /// use holidays_se::DayKind;
+    ///
+    /// let slice = DayKind::current_slice(&Stockholm.ymd(2020, 12, 25).and_hms(12, 0, 0));
+    /// assert_eq!(DayKind::Holiday, slice.kind);
+    /// assert_eq!(Stockholm.ymd(2020, 12, 24).and_hms(0, 0, 0), slice.range.start);
+    /// assert_eq!(Stockholm.ymd(2020, 12, 28).and_hms(0, 0, 0), slice.range.end);
+    /// ```
+    pub fn current_slice(dt: &DateTime<Tz>) -> DayKindSlice {
+        let kind = dt.day_kind();
+        DayKindSlice {
+            range: kind.prev_start(dt)..Self::current_end(dt),
+            kind,
+        }
+    }
+
+    /// Returns the time left from dt until the block of days of the same kind as dt ends.
+    /// ```
+    /// use chrono::{Duration, TimeZone};
+    /// use chrono_tz::Europe::Stockholm;
+    /// use holidays_se::DayKind;
+    ///
+    /// // Reopening after the christmas holidays, on Monday the 28th.
+    /// let dt = Stockholm.ymd(2020, 12, 27).and_hms(18, 0, 0);
+    /// assert_eq!(Duration::hours(6), DayKind::remaining(&dt));
+    /// ```
+    pub fn remaining(dt: &DateTime<Tz>) -> Duration {
+        Self::current_end(dt) - *dt
+    }
+
+    fn current_end(dt: &DateTime<Tz>) -> DateTime<Tz> {
+        DayKindSliceIter::from_dt(*dt, DayKindPolicy::swedish())
+            .next()
+            .expect("Slicing without an end never runs out")
+            .range
+            .end
+    }
+
+    /// Returns the total time of range that is of kind self.
+    /// Partial days at the start and end of range are only counted for the part inside range.
+    pub fn duration_in_range(&self, range: Range<DateTime<Tz>>) -> Duration {
+        slice_on_day_kind(range)
+            .filter(|slice| slice.matches_kind(*self))
+            .fold(Duration::zero(), |acc, slice| {
+                acc + (slice.range.end - slice.range.start)
+            })
+    }
+
+    /// Cycles forward in `Ord` order, wrapping around:
+    /// Weekday -> DayBeforeHoliday -> HalfDay -> Weekend -> Holiday -> Weekday.
+    pub fn next_kind(self) -> DayKind {
+        match self {
+            DayKind::Weekday => DayKind::DayBeforeHoliday,
+            DayKind::DayBeforeHoliday => DayKind::HalfDay,
+            DayKind::HalfDay => DayKind::Weekend,
+            DayKind::Weekend => DayKind::Holiday,
+            DayKind::Holiday => DayKind::Weekday,
+        }
+    }
+
+    /// The reverse of `next_kind`.
+    pub fn prev_kind(self) -> DayKind {
+        match self {
+            DayKind::Weekday => DayKind::Holiday,
+            DayKind::DayBeforeHoliday => DayKind::Weekday,
+            DayKind::HalfDay => DayKind::DayBeforeHoliday,
+            DayKind::Weekend => DayKind::HalfDay,
+            DayKind::Holiday => DayKind::Weekend,
+        }
     }
 }
 
-pub trait HasDayKind {
-    fn day_kind(&self) -> DayKind;
+/// The more special of the two kinds, `Weekday | Holiday == Holiday`.
+///
+/// Folding slices with this yields the "worst case" kind of them all.
+impl BitOr for DayKind {
+    type Output = DayKind;
+
+    fn bitor(self, rhs: DayKind) -> DayKind {
+        self.max(rhs)
+    }
 }
 
-impl<D> HasDayKind for D
-where
-    D: Datelike,
-{
-    fn day_kind(&self) -> DayKind {
-        let weekday = self.weekday();
-        if weekday == Weekday::Sun {
-            return DayKind::Holiday;
+/// The less special of the two kinds, `Weekday & Holiday == Weekday`.
+impl BitAnd for DayKind {
+    type Output = DayKind;
+
+    fn bitand(self, rhs: DayKind) -> DayKind {
+        self.min(rhs)
+    }
+}
+
+impl DayKind {
+    /// The stable string form of the kind, for storing in config files and databases:
+    /// "weekday", "day_before_holiday", "half_day", "weekend" or "holiday".
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Weekday => "weekday",
+            Self::DayBeforeHoliday => "day_before_holiday",
+            Self::HalfDay => "half_day",
+            Self::Weekend => "weekend",
+            Self::Holiday => "holiday",
         }
+    }
+}
 
-        let self_ord = self.ordinal();
-        let (_, next_holiday) = super::next_upcoming_holiday(self);
-        let next_holiday_ord = next_holiday.ordinal();
+/// Writes `DayKind::as_str`.
+impl fmt::Display for DayKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-        if self_ord == next_holiday_ord {
-            DayKind::Holiday
-        } else if self_ord == next_holiday_ord - 1 || weekday == Weekday::Sat {
-            DayKind::DayBeforeHoliday
-        } else {
-            DayKind::Weekday
+/// Parses the strings written by `Display`, exactly.
+/// ```
+/// use holidays_se::DayKind;
+///
+/// assert_eq!(Ok(DayKind::DayBeforeHoliday), "day_before_holiday".parse());
+/// assert!("DayBeforeHoliday".parse::<DayKind>().is_err());
+/// ```
+impl FromStr for DayKind {
+    type Err = ParseDayKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "weekday" => Ok(Self::Weekday),
+            "day_before_holiday" => Ok(Self::DayBeforeHoliday),
+            "half_day" => Ok(Self::HalfDay),
+            "weekend" => Ok(Self::Weekend),
+            "holiday" => Ok(Self::Holiday),
+            _ => Err(ParseDayKindError(s.to_string())),
         }
     }
 }
 
-#[derive(Clone)]
-struct SliceIterator {
+/// The input to `DayKind::from_str` was not one of the kinds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDayKindError(String);
+
+impl fmt::Display for ParseDayKindError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid day kind: {:?}", self.0)
+    }
+}
+
+impl error::Error for ParseDayKindError {}
+
+/// Extension trait for classifying dates and times, the same way as `day_kind`.
+///
+/// Implemented for `NaiveDate`, `NaiveDateTime` and `DateTime<Tz>`, plus `time::Date` with
+/// the `time-compat` feature. A `DateTime<Tz>` is classified by its local date in its own
+/// timezone. The trait is sealed, it can't be implemented outside of this crate.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{DayKind, HasDayKind};
+///
+/// assert_eq!(DayKind::Holiday, NaiveDate::from_ymd(2024, 6, 6).day_kind());
+/// ```
+pub trait HasDayKind: sealed::Sealed {
+    fn day_kind(&self) -> DayKind;
+}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for NaiveDate {}
+
+impl HasDayKind for NaiveDate {
+    fn day_kind(&self) -> DayKind {
+        DayKindPolicy::swedish().day_kind(self)
+    }
+}
+
+impl sealed::Sealed for NaiveDateTime {}
+
+impl HasDayKind for NaiveDateTime {
+    fn day_kind(&self) -> DayKind {
+        self.date().day_kind()
+    }
+}
+
+impl sealed::Sealed for DateTime<Tz> {}
+
+impl HasDayKind for DateTime<Tz> {
+    fn day_kind(&self) -> DayKind {
+        self.naive_local().date().day_kind()
+    }
+}
+
+/// Iterator of `DayKindSlice`s, see `slice_on_day_kind`.
+///
+/// Holds a one slice lookahead, so the next slice can be inspected without consuming it:
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::Europe::Stockholm;
+/// use holidays_se::{slice_on_day_kind, DayKind};
+///
+/// let start = Stockholm.ymd(2020, 4, 8).and_hms(0, 0, 0); // Wed before Good Friday.
+/// let end = Stockholm.ymd(2020, 4, 15).and_hms(0, 0, 0);
+/// let mut iter = slice_on_day_kind(start..end);
+///
+/// let mut transitions = Vec::new();
+/// while let Some(slice) = iter.next() {
+///     if let Some(next) = iter.peek() {
+///         transitions.push((slice.kind, next.kind));
+///     }
+/// }
+///
+/// assert_eq!((DayKind::Weekday, DayKind::DayBeforeHoliday), transitions[0]);
+/// assert_eq!((DayKind::DayBeforeHoliday, DayKind::Holiday), transitions[1]);
+/// assert_eq!(5, transitions.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DayKindSliceIter {
     // Stepped forward.
     start: chrono::DateTime<Tz>,
     end: Option<chrono::DateTime<Tz>>,
+    peeked: Option<Option<DayKindSlice>>,
+    policy: DayKindPolicy,
 }
 
-impl SliceIterator {
-    pub fn from_dt(start: chrono::DateTime<Tz>) -> Self {
-        Self { start, end: None }
+/// A `DayKindSliceIter` wrapped in `std::iter::Peekable`.
+pub type PeekableDayKindSliceIter = iter::Peekable<DayKindSliceIter>;
+
+impl DayKindSliceIter {
+    pub(crate) fn from_dt(start: chrono::DateTime<Tz>, policy: DayKindPolicy) -> Self {
+        Self {
+            start,
+            end: None,
+            peeked: None,
+            policy,
+        }
     }
-}
 
-impl iter::Iterator for SliceIterator {
-    type Item = DayKindSlice;
+    /// Returns the next slice without consuming it.
+    pub fn peek(&mut self) -> Option<&DayKindSlice> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.advance());
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
+        self.peeked.as_ref().and_then(Option::as_ref)
+    }
+
+    /// Wraps self in a `std::iter::Peekable`.
+    pub fn peekable(self) -> PeekableDayKindSliceIter {
+        Iterator::peekable(self)
+    }
+
+    fn advance(&mut self) -> Option<DayKindSlice> {
         if self.end.map(|end| end <= self.start).unwrap_or(false) {
             return None;
         }
 
-        let start_kind = self.start.day_kind();
+        let start_kind = self.policy.day_kind_at(&self.start);
         let mut step = self.start;
 
         loop {
-            let next_day = (step.date() + Duration::days(1)).and_hms(0, 0, 0);
+            // The next midnight, or the eve cutoff of the day.
+            let boundary = self.policy.next_boundary(&step);
 
             // We reached the end of given range.
             if let Some(end) = self.end {
-                if end < next_day {
+                if end < boundary {
                     let res = DayKindSlice {
                         range: (self.start..end),
                         kind: start_kind,
@@ -89,46 +406,914 @@ impl iter::Iterator for SliceIterator {
                 }
             }
 
-            if next_day.day_kind() != start_kind {
+            if self.policy.day_kind_at(&boundary) != start_kind {
                 let res = DayKindSlice {
-                    range: (self.start..next_day),
+                    range: (self.start..boundary),
                     kind: start_kind,
                 };
-                self.start = next_day;
+                self.start = boundary;
                 return Some(res);
             }
 
-            step = next_day;
+            step = boundary;
+        }
+    }
+}
+
+impl iter::Iterator for DayKindSliceIter {
+    type Item = DayKindSlice;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.advance(),
         }
     }
 }
 
+/// Returns dt + within, clamped to the last midnight slices can be stepped to without
+/// overflowing, the day before the last representable date.
+fn search_end(dt: &DateTime<Tz>, within: Duration) -> DateTime<Tz> {
+    let last = NaiveDate::MAX
+        .pred_opt()
+        .and_then(|date| dt.timezone().from_local_date(&date).earliest())
+        .expect("The day before the last date exists")
+        .and_hms(0, 0, 0);
+
+    dt.checked_add_signed(within)
+        .map_or(last, |end| cmp::min(end, last))
+}
+
 /// Returns an iterator of DayKindSlices.
-pub fn slice_on_day_kind(range: Range<DateTime<Tz>>) -> impl Iterator<Item = DayKindSlice> {
-    SliceIterator {
+pub fn slice_on_day_kind(range: Range<DateTime<Tz>>) -> DayKindSliceIter {
+    slice_on_day_kind_with(range, DayKindPolicy::swedish())
+}
+
+/// Same as `slice_on_day_kind`, classifying days by policy.
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::Europe::Stockholm;
+/// use holidays_se::{slice_on_day_kind_with, DayKind, DayKindPolicy};
+///
+/// // Friday through Sunday.
+/// let start = Stockholm.ymd(2020, 9, 18).and_hms(0, 0, 0);
+/// let end = Stockholm.ymd(2020, 9, 21).and_hms(0, 0, 0);
+///
+/// let policy = DayKindPolicy::swedish().with_weekends();
+/// let kinds = slice_on_day_kind_with(start..end, policy)
+///     .map(|slice| slice.kind)
+///     .collect::<Vec<_>>();
+/// assert_eq!(vec![DayKind::Weekday, DayKind::Weekend], kinds);
+/// ```
+pub fn slice_on_day_kind_with(
+    range: Range<DateTime<Tz>>,
+    policy: DayKindPolicy,
+) -> DayKindSliceIter {
+    DayKindSliceIter {
         start: range.start,
         end: Some(range.end),
+        peeked: None,
+        policy,
     }
 }
 
+/// Returns every date in range together with its `DayKind`, in order.
+///
+/// Consecutive days of the same kind are classified together as a slice,
+/// instead of every date being classified on its own.
+pub fn days_in_range_by_kind(
+    range: Range<NaiveDate>,
+) -> impl Iterator<Item = (NaiveDate, DayKind)> {
+    slice_on_day_kind_dates(range).flat_map(|slice| {
+        let kind = slice.kind;
+        crate::dates_in(slice.range).map(move |d| (d, kind))
+    })
+}
+
+/// Returns the greatest kind of any time within range, using the `Ord` of `DayKind`.
+/// With the default classification that is Weekday < DayBeforeHoliday < Holiday,
+/// so any holiday time in range gives `DayKind::Holiday`.
+///
+/// Stops at the first holiday. An empty range gives `DayKind::Weekday`.
+/// ```
+/// use chrono::TimeZone;
+/// use chrono_tz::Europe::Stockholm;
+/// use holidays_se::{max_kind_in_range, DayKind};
+///
+/// // Monday through Friday, the 23rd being the day before christmas eve.
+/// let start = Stockholm.ymd(2020, 12, 21).and_hms(0, 0, 0);
+/// let end = Stockholm.ymd(2020, 12, 24).and_hms(0, 0, 0);
+/// assert_eq!(DayKind::DayBeforeHoliday, max_kind_in_range(start..end));
+/// ```
+pub fn max_kind_in_range(range: Range<DateTime<Tz>>) -> DayKind {
+    let mut max = DayKind::Weekday;
+    for slice in slice_on_day_kind(range) {
+        max = max.max(slice.kind);
+        if max == DayKind::Holiday {
+            break;
+        }
+    }
+    max
+}
+
+/// Returns every date in range together with its `DayKind`, in order, same as `day_kind`.
+///
+/// The holidays of the years in range are computed once up front, making this the fastest
+/// way to classify many dates.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{classify_days, DayKind};
+///
+/// let start = NaiveDate::from_ymd(2024, 1, 1);
+/// let end = NaiveDate::from_ymd(2025, 1, 1);
+/// let holidays = classify_days(start..end)
+///     .filter(|(_, kind)| *kind == DayKind::Holiday)
+///     .count();
+/// assert_eq!(66, holidays);
+/// ```
+pub fn classify_days(range: Range<NaiveDate>) -> impl Iterator<Item = (NaiveDate, DayKind)> {
+    // The year of range.end is needed for the day after the last date.
+    let holidays = (range.start.year()..=range.end.year())
+        .flat_map(crate::holidays_in_year)
+        .collect::<Vec<_>>();
+
+    let policy = DayKindPolicy::swedish();
+    crate::dates_in(range).map(move |date| {
+        let (kind, _) = policy.classify_by(date, |d| {
+            holidays
+                .binary_search_by_key(&d, |(date, _)| *date)
+                .ok()
+                .map(|i| holidays[i].1)
+        });
+        (date, kind)
+    })
+}
+
+/// Same as `slice_on_day_kind` but for whole days.
+pub fn slice_on_day_kind_dates(range: Range<NaiveDate>) -> impl Iterator<Item = DayKindDateSlice> {
+    let midnight = |d: NaiveDate| Stockholm.from_local_date(&d).unwrap().and_hms(0, 0, 0);
+
+    slice_on_day_kind(midnight(range.start)..midnight(range.end)).map(|slice| DayKindDateSlice {
+        range: slice.range.start.naive_local().date()..slice.range.end.naive_local().date(),
+        kind: slice.kind,
+    })
+}
+
 pub fn day_kind<D>(d: &D) -> DayKind
 where
     D: Datelike,
 {
-    d.day_kind()
+    DayKindPolicy::swedish().day_kind(d)
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Same as `day_kind`, classifying d by policy. Same as `DayKindPolicy::day_kind`.
+pub fn day_kind_with<D>(d: &D, policy: DayKindPolicy) -> DayKind
+where
+    D: Datelike,
+{
+    policy.day_kind(d)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DayKindSlice {
     pub range: Range<chrono::DateTime<Tz>>,
     pub kind: DayKind,
 }
 
+impl DayKindSlice {
+    /// Creates a slice of whole days, all of the same kind.
+    ///
+    /// start must be before end and both must be at midnight, Stockholm time.
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Result<DayKindSlice, SliceError> {
+        if start >= end {
+            return Err(SliceError::EmptyRange);
+        }
+
+        let is_midnight = |dt: DateTime<Tz>| {
+            let local = dt.with_timezone(&Stockholm);
+            local == local.date().and_hms(0, 0, 0)
+        };
+        if !is_midnight(start) || !is_midnight(end) {
+            return Err(SliceError::NotMidnight);
+        }
+
+        let kind = start.day_kind();
+        match slice_on_day_kind(start..end).next() {
+            Some(slice) if slice.range.end == end => Ok(DayKindSlice {
+                range: start..end,
+                kind,
+            }),
+            _ => Err(SliceError::NonHomogeneous),
+        }
+    }
+
+    /// Returns true if the slice is of the given kind, regardless of its range.
+    pub fn matches_kind(&self, kind: DayKind) -> bool {
+        self.kind == kind
+    }
+
+    /// Returns a copy of the slice, starting at midnight of the day it starts on.
+    pub fn shift_to_start_of_day(&self) -> DayKindSlice {
+        DayKindSlice {
+            range: self.range.start.date().and_hms(0, 0, 0)..self.range.end,
+            kind: self.kind,
+        }
+    }
+
+    /// Returns a copy of the slice, ending at midnight after the day it ends on.
+    /// A slice already ending at midnight is left as is.
+    pub fn shift_to_end_of_day(&self) -> DayKindSlice {
+        let end = self.range.end;
+        let end = if end == end.date().and_hms(0, 0, 0) {
+            end
+        } else {
+            (end.date() + Duration::days(1)).and_hms(0, 0, 0)
+        };
+
+        DayKindSlice {
+            range: self.range.start..end,
+            kind: self.kind,
+        }
+    }
+}
+
+/// Consecutive whole days of the same kind, see `slice_on_day_kind_dates`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DayKindDateSlice {
+    pub range: Range<NaiveDate>,
+    pub kind: DayKind,
+}
+
+impl DayKindDateSlice {
+    /// Displays the slice with the English names of its holidays instead of its kind,
+    /// e.g. "2020-04-12..2020-04-14 (Easter Sunday, Easter Monday)".
+    /// Slices without any named holidays are displayed as usual.
+    pub fn display_with_holidays(&self) -> impl fmt::Display + '_ {
+        DisplayWithHolidays {
+            slice: self,
+            swedish: false,
+        }
+    }
+
+    /// Same as `display_with_holidays` but in Swedish,
+    /// e.g. "2020-04-12..2020-04-14 (Påskdagen, Annandag påsk)".
+    /// Slices without named holidays get the Swedish name of their kind.
+    pub fn display_swedish(&self) -> impl fmt::Display + '_ {
+        DisplayWithHolidays {
+            slice: self,
+            swedish: true,
+        }
+    }
+}
+
+/// Formats as "2020-04-12..2020-04-14 (holiday)", the end is exclusive.
+impl fmt::Display for DayKindDateSlice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}..{} ({})",
+            self.range.start, self.range.end, self.kind
+        )
+    }
+}
+
+struct DisplayWithHolidays<'a> {
+    slice: &'a DayKindDateSlice,
+    swedish: bool,
+}
+
+impl fmt::Display for DisplayWithHolidays<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let holidays = crate::dates_in(self.slice.range.clone())
+            .filter_map(crate::holiday_at)
+            .map(|h| {
+                if self.swedish {
+                    h.to_string()
+                } else {
+                    h.english_name().to_string()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let label = match (holidays.is_empty(), self.swedish, self.slice.kind) {
+            (false, _, _) => holidays.join(", "),
+            (true, false, kind) => kind.to_string(),
+            (true, true, DayKind::Weekday) => "Vardag".to_string(),
+            (true, true, DayKind::DayBeforeHoliday) => "Dag före helgdag".to_string(),
+            (true, true, DayKind::HalfDay) => "Halvdag".to_string(),
+            (true, true, DayKind::Weekend) => "Helg".to_string(),
+            (true, true, DayKind::Holiday) => "Helgdag".to_string(),
+        };
+
+        write!(
+            f,
+            "{}..{} ({})",
+            self.slice.range.start, self.slice.range.end, label
+        )
+    }
+}
+
+/// Succeeds if the whole range is of a single kind.
+impl TryFrom<Range<DateTime<Tz>>> for DayKindSlice {
+    type Error = MultipleKindsError;
+
+    fn try_from(range: Range<DateTime<Tz>>) -> Result<Self, Self::Error> {
+        let mut slices = slice_on_day_kind(range.clone());
+        match (slices.next(), slices.next()) {
+            (Some(slice), None) => Ok(slice),
+            _ => Err(MultipleKindsError { range }),
+        }
+    }
+}
+
+/// The range did not cover exactly one `DayKind`.
+/// Either it spans several kinds, or it's empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipleKindsError {
+    pub range: Range<DateTime<Tz>>,
+}
+
+impl fmt::Display for MultipleKindsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} - {} is not of a single day kind",
+            self.range.start, self.range.end
+        )
+    }
+}
+
+impl error::Error for MultipleKindsError {}
+
+/// Errors from `DayKindSlice::new`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// The start is not before the end.
+    EmptyRange,
+    /// The start or end is not at midnight.
+    NotMidnight,
+    /// The range spans more than one kind of day.
+    NonHomogeneous,
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptyRange => f.write_str("slice must start before it ends"),
+            Self::NotMidnight => f.write_str("slice must start and end at midnight"),
+            Self::NonHomogeneous => f.write_str("slice spans more than one day kind"),
+        }
+    }
+}
+
+impl error::Error for SliceError {}
+
+/// A point in time, classified by the kind of day it's on and its local time of day.
+///
+/// Ordered by kind first, then time of day and lastly the day itself.
+/// So for the same kind, earlier times of day come first regardless of date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayKindInstant {
+    pub kind: DayKind,
+    pub time_of_day: NaiveTime,
+    pub day: NaiveDate,
+}
+
+/// Classifies the day of dt, keeping its local time of day.
+pub fn classify_datetime(dt: DateTime<Tz>) -> DayKindInstant {
+    let local = dt.naive_local();
+    DayKindInstant {
+        kind: day_kind(&local.date()),
+        time_of_day: local.time(),
+        day: local.date(),
+    }
+}
+
+/// A day with its kind and the holidays behind it, returned by `classify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassifiedDay {
+    pub kind: DayKind,
+    /// The named holiday of the day, if any.
+    pub holiday: Option<Holiday>,
+    /// The named holiday of the next day, if any.
+    pub eve_of: Option<Holiday>,
+    pub weekday: Weekday,
+    /// A working day squeezed in between days off, see `is_klamdag_recommended`.
+    pub klamdag: bool,
+}
+
+/// Classifies d the same way as `day_kind`, along with the holiday of the day and
+/// the holiday it is the eve of.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{classify, DayKind, Holiday};
+///
+/// let day = classify(&NaiveDate::from_ymd(2024, 12, 24));
+/// assert_eq!(DayKind::Holiday, day.kind);
+/// assert_eq!(Some(Holiday::Julafton), day.holiday);
+/// assert_eq!(Some(Holiday::Juldagen), day.eve_of);
+/// ```
+pub fn classify<D>(d: &D) -> ClassifiedDay
+where
+    D: Datelike,
+{
+    let date = NaiveDate::from_yo(d.year(), d.ordinal());
+    ClassifiedDay {
+        kind: date.day_kind(),
+        holiday: holiday_at(date),
+        eve_of: holiday_at(date.succ()),
+        weekday: date.weekday(),
+        klamdag: is_klamdag_recommended(date),
+    }
+}
+
+/// Same as `day_kind`, along with the reason for the classification.
+/// Uses `DayKindPolicy::swedish`, see `DayKindPolicy::day_kind_with_reason`.
+///
+/// A named holiday takes precedence over the weekday rules, so Påskafton is
+/// the eve of Påskdagen rather than a Saturday.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{day_kind_with_reason, DayKind, Holiday, Reason};
+///
+/// assert_eq!(
+///     (DayKind::DayBeforeHoliday, Reason::EveOf(Holiday::Langfredagen)),
+///     day_kind_with_reason(&NaiveDate::from_ymd(2024, 3, 28))
+/// );
+/// ```
+pub fn day_kind_with_reason<D>(d: &D) -> (DayKind, Reason)
+where
+    D: Datelike,
+{
+    DayKindPolicy::swedish().day_kind_with_reason(d)
+}
+
+/// A collected sequence of contiguous `DayKindSlice`s.
+///
+/// Collecting or extending with slices that don't follow directly after
+/// each other panics in debug builds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayKindSliceStream {
+    slices: Vec<DayKindSlice>,
+}
+
+impl DayKindSliceStream {
+    pub fn from_range(range: Range<DateTime<Tz>>) -> Self {
+        slice_on_day_kind(range).collect()
+    }
+
+    /// Returns the sum of the duration of all slices.
+    pub fn total_duration(&self) -> Duration {
+        self.slices.iter().fold(Duration::zero(), |acc, slice| {
+            acc + (slice.range.end - slice.range.start)
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.slices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slices.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, DayKindSlice> {
+        self.slices.iter()
+    }
+
+    fn push(&mut self, slice: DayKindSlice) {
+        debug_assert!(
+            slice.range.start < slice.range.end,
+            "Slice {:?} is empty",
+            slice
+        );
+        if let Some(last) = self.slices.last() {
+            debug_assert!(
+                last.range.end == slice.range.start,
+                "Slice {:?} does not follow directly after {:?}",
+                slice,
+                last
+            );
+        }
+
+        self.slices.push(slice);
+    }
+}
+
+impl iter::FromIterator<DayKindSlice> for DayKindSliceStream {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = DayKindSlice>,
+    {
+        let mut stream = Self::default();
+        stream.extend(iter);
+        stream
+    }
+}
+
+impl iter::Extend<DayKindSlice> for DayKindSliceStream {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = DayKindSlice>,
+    {
+        for slice in iter {
+            self.push(slice);
+        }
+    }
+}
+
+impl iter::IntoIterator for DayKindSliceStream {
+    type Item = DayKindSlice;
+    type IntoIter = std::vec::IntoIter<DayKindSlice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slices.into_iter()
+    }
+}
+
+impl<'a> iter::IntoIterator for &'a DayKindSliceStream {
+    type Item = &'a DayKindSlice;
+    type IntoIter = std::slice::Iter<'a, DayKindSlice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slices.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
-    use chrono_tz::Europe::Stockholm;
+
+    #[test]
+    fn test_day_kind_cycle() {
+        use DayKind::*;
+
+        let kinds = [Weekday, DayBeforeHoliday, HalfDay, Weekend, Holiday];
+        assert!(kinds.windows(2).all(|w| w[0] < w[1]));
+
+        for kind in kinds {
+            assert_eq!(kind, (0..5).fold(kind, |k, _| k.next_kind()));
+            assert_eq!(kind, (0..5).fold(kind, |k, _| k.prev_kind()));
+            assert_eq!(kind, kind.next_kind().prev_kind());
+            if kind != Holiday {
+                assert!(kind < kind.next_kind());
+            }
+        }
+
+        assert_eq!(DayBeforeHoliday, Weekday.next_kind());
+        assert_eq!(HalfDay, DayBeforeHoliday.next_kind());
+        assert_eq!(Weekend, HalfDay.next_kind());
+        assert_eq!(Holiday, Weekend.next_kind());
+        assert_eq!(Weekday, Holiday.next_kind());
+        assert_eq!(Holiday, Weekday.prev_kind());
+    }
+
+    #[test]
+    fn test_day_kind_bit_ops() {
+        use DayKind::*;
+
+        // (lhs, rhs, lhs | rhs, lhs & rhs)
+        let table = [
+            (Weekday, Weekday, Weekday, Weekday),
+            (Weekday, DayBeforeHoliday, DayBeforeHoliday, Weekday),
+            (Weekday, Holiday, Holiday, Weekday),
+            (DayBeforeHoliday, Weekday, DayBeforeHoliday, Weekday),
+            (
+                DayBeforeHoliday,
+                DayBeforeHoliday,
+                DayBeforeHoliday,
+                DayBeforeHoliday,
+            ),
+            (DayBeforeHoliday, Holiday, Holiday, DayBeforeHoliday),
+            (Holiday, Weekday, Holiday, Weekday),
+            (Holiday, DayBeforeHoliday, Holiday, DayBeforeHoliday),
+            (Holiday, Holiday, Holiday, Holiday),
+        ];
+
+        for (lhs, rhs, or, and) in table.iter().copied() {
+            assert_eq!(or, lhs | rhs, "{:?} | {:?}", lhs, rhs);
+            assert_eq!(and, lhs & rhs, "{:?} & {:?}", lhs, rhs);
+        }
+    }
+
+    #[test]
+    fn test_classify_datetime() {
+        // A Thursday and Långfredagen.
+        let weekday = Stockholm.ymd(2020, 9, 17);
+        let holiday = Stockholm.ymd(2020, 4, 10);
+
+        for (date, kind) in [(weekday, DayKind::Weekday), (holiday, DayKind::Holiday)] {
+            let times = [(0, 0), (6, 0), (7, 0), (17, 0), (23, 59)];
+            let instants = times
+                .iter()
+                .map(|(h, m)| classify_datetime(date.and_hms(*h, *m, 0)))
+                .collect::<Vec<_>>();
+
+            for (instant, (h, m)) in instants.iter().zip(times.iter()) {
+                assert_eq!(kind, instant.kind);
+                assert_eq!(date.naive_local(), instant.day);
+                assert_eq!(NaiveTime::from_hms(*h, *m, 0), instant.time_of_day);
+            }
+
+            assert!(instants.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let d = |m, d| NaiveDate::from_ymd(2024, m, d);
+
+        let day = classify(&d(3, 28));
+        assert_eq!(DayKind::DayBeforeHoliday, day.kind);
+        assert_eq!(None, day.holiday);
+        assert_eq!(Some(Holiday::Langfredagen), day.eve_of);
+        assert_eq!(Weekday::Thu, day.weekday);
+        assert!(!day.klamdag);
+
+        // The Friday after Kristi himmelsfärdsdag.
+        assert!(classify(&d(5, 10)).klamdag);
+
+        // An ordinary Saturday is the day before a holiday without being the eve of one.
+        let day = classify(&d(3, 9));
+        assert_eq!(DayKind::DayBeforeHoliday, day.kind);
+        assert_eq!((None, None), (day.holiday, day.eve_of));
+
+        let day = classify(&Stockholm.ymd(2024, 12, 31));
+        assert_eq!(DayKind::Holiday, day.kind);
+        assert_eq!(Some(Holiday::Nyarsafton), day.holiday);
+        assert_eq!(Some(Holiday::Nyarsdagen), day.eve_of);
+    }
+
+    #[test]
+    fn test_day_kind_with_reason() {
+        let d = |m, d| NaiveDate::from_ymd(2024, m, d);
+
+        let cases = [
+            (d(3, 29), Reason::Holiday(Holiday::Langfredagen)),
+            (d(3, 10), Reason::Sunday),
+            (d(3, 30), Reason::EveOf(Holiday::Paskdagen)),
+            (d(3, 9), Reason::Saturday),
+            (d(3, 8), Reason::Weekday),
+        ];
+        for (date, reason) in &cases {
+            assert_eq!(*reason, day_kind_with_reason(date).1, "{}", date);
+        }
+
+        let mut date = d(1, 1);
+        while date.year() == 2024 {
+            assert_eq!(day_kind(&date), day_kind_with_reason(&date).0, "{}", date);
+            date = date.succ();
+        }
+    }
+
+    #[test]
+    fn test_day_kind_instant_ordering() {
+        let morning_holiday = classify_datetime(Stockholm.ymd(2020, 4, 10).and_hms(6, 0, 0));
+        let evening_weekday = classify_datetime(Stockholm.ymd(2020, 9, 17).and_hms(23, 0, 0));
+        assert!(evening_weekday < morning_holiday);
+
+        // Same kind, the time of day decides before the date.
+        let early = classify_datetime(Stockholm.ymd(2020, 9, 18).and_hms(6, 0, 0));
+        let late = classify_datetime(Stockholm.ymd(2020, 9, 17).and_hms(7, 0, 0));
+        assert!(early < late);
+    }
+
+    #[test]
+    fn test_slice_try_from_single_kind() {
+        let start = Stockholm.ymd(2020, 9, 17).and_hms(8, 0, 0);
+        let end = Stockholm.ymd(2020, 9, 17).and_hms(17, 0, 0);
+
+        assert_eq!(
+            Ok(DayKindSlice {
+                range: start..end,
+                kind: DayKind::Weekday,
+            }),
+            DayKindSlice::try_from(start..end)
+        );
+    }
+
+    #[test]
+    fn test_slice_try_from_multiple_kinds() {
+        // Friday evening into Saturday.
+        let start = Stockholm.ymd(2020, 9, 18).and_hms(20, 0, 0);
+        let end = Stockholm.ymd(2020, 9, 19).and_hms(2, 0, 0);
+
+        assert_eq!(
+            Err(MultipleKindsError { range: start..end }),
+            DayKindSlice::try_from(start..end)
+        );
+        assert!(DayKindSlice::try_from(start..start).is_err());
+    }
+
+    #[test]
+    fn test_slice_on_day_kind_dates() {
+        let d = |m, d| NaiveDate::from_ymd(2020, m, d);
+
+        // Skärtorsdagen through the Tuesday after Easter.
+        assert_eq!(
+            vec![
+                DayKindDateSlice {
+                    range: d(4, 9)..d(4, 10),
+                    kind: DayKind::DayBeforeHoliday,
+                },
+                DayKindDateSlice {
+                    range: d(4, 10)..d(4, 11),
+                    kind: DayKind::Holiday,
+                },
+                DayKindDateSlice {
+                    range: d(4, 11)..d(4, 12),
+                    kind: DayKind::DayBeforeHoliday,
+                },
+                DayKindDateSlice {
+                    range: d(4, 12)..d(4, 14),
+                    kind: DayKind::Holiday,
+                },
+                DayKindDateSlice {
+                    range: d(4, 14)..d(4, 15),
+                    kind: DayKind::Weekday,
+                },
+            ],
+            slice_on_day_kind_dates(d(4, 9)..d(4, 15)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_display_date_slices() {
+        let easter = DayKindDateSlice {
+            range: NaiveDate::from_ymd(2020, 4, 12)..NaiveDate::from_ymd(2020, 4, 14),
+            kind: DayKind::Holiday,
+        };
+        assert_eq!("2020-04-12..2020-04-14 (holiday)", easter.to_string());
+        assert_eq!(
+            "2020-04-12..2020-04-14 (Easter Sunday, Easter Monday)",
+            easter.display_with_holidays().to_string()
+        );
+        assert_eq!(
+            "2020-04-12..2020-04-14 (Påskdagen, Annandag påsk)",
+            easter.display_swedish().to_string()
+        );
+
+        let weekdays = DayKindDateSlice {
+            range: NaiveDate::from_ymd(2020, 9, 14)..NaiveDate::from_ymd(2020, 9, 19),
+            kind: DayKind::Weekday,
+        };
+        assert_eq!("2020-09-14..2020-09-19 (weekday)", weekdays.to_string());
+        assert_eq!(
+            weekdays.to_string(),
+            weekdays.display_with_holidays().to_string()
+        );
+        assert_eq!(
+            "2020-09-14..2020-09-19 (Vardag)",
+            weekdays.display_swedish().to_string()
+        );
+    }
+
+    #[test]
+    fn test_day_kind_string_round_trip() {
+        let mut kind = DayKind::Weekday;
+        for _ in 0..5 {
+            assert_eq!(Ok(kind), kind.to_string().parse());
+            kind = kind.next_kind();
+        }
+
+        assert_eq!("day_before_holiday", DayKind::DayBeforeHoliday.to_string());
+        assert_eq!(
+            Err(ParseDayKindError(" holiday".to_string())),
+            " holiday".parse::<DayKind>()
+        );
+    }
+
+    #[test]
+    fn test_classify_days() {
+        let start = NaiveDate::from_ymd(2003, 12, 1);
+        let end = NaiveDate::from_ymd(2006, 1, 1);
+
+        let classified = classify_days(start..end).collect::<Vec<_>>();
+        assert_eq!(
+            days_in_range_by_kind(start..end).collect::<Vec<_>>(),
+            classified
+        );
+        assert_eq!((end - start).num_days() as usize, classified.len());
+
+        assert_eq!(0, classify_days(end..end).count());
+    }
+
+    #[test]
+    fn test_around_new_year() {
+        for year in 2018..=2023 {
+            // Nyårsafton is a holiday in itself.
+            let nyarsafton = NaiveDate::from_ymd(year, 12, 31);
+            assert_eq!(DayKind::Holiday, day_kind(&nyarsafton), "{}", nyarsafton);
+            // The day before is too, unless it's a Sunday.
+            let expected = if nyarsafton.pred().weekday() == Weekday::Sun {
+                DayKind::Holiday
+            } else {
+                DayKind::DayBeforeHoliday
+            };
+            assert_eq!(
+                expected,
+                day_kind(&nyarsafton.pred()),
+                "{}",
+                nyarsafton.pred()
+            );
+            assert_eq!(
+                DayKind::Holiday,
+                day_kind(&nyarsafton.succ()),
+                "{}",
+                nyarsafton.succ()
+            );
+
+            // Same thing, with time.
+            let evening = Stockholm.ymd(year, 12, 30).and_hms(23, 0, 0);
+            assert_eq!(expected, day_kind(&evening));
+        }
+    }
+
+    #[test]
+    fn test_slices_across_new_year() {
+        // Nyårsafton 2022 is a Saturday, the slices must not break at the year boundary.
+        let start = Stockholm.ymd(2022, 12, 29).and_hms(0, 0, 0);
+        let end = Stockholm.ymd(2023, 1, 4).and_hms(0, 0, 0);
+        let slices = slice_on_day_kind(start..end).collect::<Vec<_>>();
+
+        let midnight = |y, m, d| Stockholm.ymd(y, m, d).and_hms(0, 0, 0);
+        assert_eq!(
+            vec![
+                (
+                    midnight(2022, 12, 29)..midnight(2022, 12, 30),
+                    DayKind::Weekday
+                ),
+                (
+                    midnight(2022, 12, 30)..midnight(2022, 12, 31),
+                    DayKind::DayBeforeHoliday
+                ),
+                (
+                    midnight(2022, 12, 31)..midnight(2023, 1, 2),
+                    DayKind::Holiday
+                ),
+                (midnight(2023, 1, 2)..midnight(2023, 1, 4), DayKind::Weekday),
+            ],
+            slices
+                .into_iter()
+                .map(|s| (s.range, s.kind))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_new_slice() {
+        let midnight = |m, d| Stockholm.ymd(2020, m, d).and_hms(0, 0, 0);
+
+        // Monday through Friday.
+        assert_eq!(
+            Ok(DayKindSlice {
+                range: midnight(9, 14)..midnight(9, 19),
+                kind: DayKind::Weekday,
+            }),
+            DayKindSlice::new(midnight(9, 14), midnight(9, 19))
+        );
+
+        // Påskdagen and Annandag påsk.
+        assert_eq!(
+            Ok(DayKind::Holiday),
+            DayKindSlice::new(midnight(4, 12), midnight(4, 14)).map(|s| s.kind)
+        );
+    }
+
+    #[test]
+    fn test_new_slice_errors() {
+        let midnight = |m, d| Stockholm.ymd(2020, m, d).and_hms(0, 0, 0);
+
+        assert_eq!(
+            Err(SliceError::EmptyRange),
+            DayKindSlice::new(midnight(9, 14), midnight(9, 14))
+        );
+        assert_eq!(
+            Err(SliceError::EmptyRange),
+            DayKindSlice::new(midnight(9, 15), midnight(9, 14))
+        );
+        assert_eq!(
+            Err(SliceError::NotMidnight),
+            DayKindSlice::new(Stockholm.ymd(2020, 9, 14).and_hms(8, 0, 0), midnight(9, 15))
+        );
+        // Into the weekend.
+        assert_eq!(
+            Err(SliceError::NonHomogeneous),
+            DayKindSlice::new(midnight(9, 14), midnight(9, 20))
+        );
+    }
+
+    #[test]
+    fn test_fold_slices_with_bit_or() {
+        // Thursday before Easter through Saturday.
+        let start = Stockholm.ymd(2020, 4, 9).and_hms(0, 0, 0);
+        let end = Stockholm.ymd(2020, 4, 12).and_hms(0, 0, 0);
+
+        let worst = slice_on_day_kind(start..end)
+            .map(|s| s.kind)
+            .fold(DayKind::Weekday, BitOr::bitor);
+        assert_eq!(DayKind::Holiday, worst);
+    }
 
     #[test]
     fn slice_a_single_weekday() {
@@ -490,4 +1675,301 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_prev_start() {
+        // Annandag jul 2020, a Saturday.
+        let dt = Stockholm.ymd(2020, 12, 26).and_hms(13, 37, 0);
+        assert_eq!(
+            Stockholm.ymd(2020, 12, 24).and_hms(0, 0, 0),
+            DayKind::Holiday.prev_start(&dt),
+            "The christmas block begins on christmas eve"
+        );
+        assert_eq!(
+            Stockholm.ymd(2020, 12, 23).and_hms(0, 0, 0),
+            DayKind::DayBeforeHoliday.prev_start(&dt)
+        );
+        assert_eq!(
+            Stockholm.ymd(2020, 12, 21).and_hms(0, 0, 0),
+            DayKind::Weekday.prev_start(&dt)
+        );
+
+        // Back across the new year.
+        let dt = Stockholm.ymd(2021, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(
+            Stockholm.ymd(2020, 12, 31).and_hms(0, 0, 0),
+            DayKind::Holiday.prev_start(&dt)
+        );
+    }
+
+    #[test]
+    fn test_current_slice() {
+        // Midnight belongs to the day starting, not the one ending.
+        let dt = Stockholm.ymd(2020, 10, 24).and_hms(0, 0, 0);
+        let slice = DayKind::current_slice(&dt);
+        assert_eq!(DayKind::DayBeforeHoliday, slice.kind);
+        assert_eq!(
+            dt..Stockholm.ymd(2020, 10, 25).and_hms(0, 0, 0),
+            slice.range
+        );
+
+        let dt = Stockholm.ymd(2020, 10, 21).and_hms(13, 37, 0);
+        let slice = DayKind::current_slice(&dt);
+        assert_eq!(DayKind::Weekday, slice.kind);
+        assert_eq!(
+            Stockholm.ymd(2020, 10, 19).and_hms(0, 0, 0)
+                ..Stockholm.ymd(2020, 10, 24).and_hms(0, 0, 0),
+            slice.range
+        );
+    }
+
+    #[test]
+    fn test_remaining() {
+        // The Thursday before Midsommarafton 2020.
+        let dt = Stockholm.ymd(2020, 6, 18).and_hms(17, 0, 0);
+        assert_eq!(Duration::hours(7), DayKind::remaining(&dt));
+
+        // Midsommarafton, across the whole weekend.
+        let dt = Stockholm.ymd(2020, 6, 19).and_hms(0, 0, 0);
+        assert_eq!(Duration::days(3), DayKind::remaining(&dt));
+        assert_eq!(
+            DayKind::current_slice(&dt).range.end - dt,
+            DayKind::remaining(&dt)
+        );
+    }
+
+    #[test]
+    fn test_next_start_within() {
+        let dt = Stockholm.ymd(2020, 10, 21).and_hms(13, 37, 0);
+        assert_eq!(
+            Some(dt),
+            DayKind::Weekday.next_start_within(&dt, Duration::hours(1))
+        );
+        assert_eq!(
+            None,
+            DayKind::Weekend.next_start_within(&dt, Duration::days(366))
+        );
+        assert_eq!(
+            None,
+            DayKind::Weekday.next_start_within(&dt, Duration::zero())
+        );
+        assert_eq!(
+            Some(Stockholm.ymd(2020, 10, 25).and_hms(0, 0, 0)),
+            DayKind::Holiday.next_start_within(&dt, Duration::max_value())
+        );
+
+        // Stops before the last representable date.
+        let late = Stockholm.ymd(262_142, 12, 20).and_hms(0, 0, 0);
+        assert_eq!(
+            None,
+            DayKind::Weekend.next_start_within(&late, Duration::max_value())
+        );
+    }
+
+    #[test]
+    fn test_nth_next_start() {
+        // Christmas eve 2020, a Thursday.
+        let dt = Stockholm.ymd(2020, 12, 24).and_hms(13, 37, 0);
+        assert_eq!(None, DayKind::Holiday.nth_next_start(&dt, 0));
+        assert_eq!(Some(dt), DayKind::Holiday.nth_next_start(&dt, 1));
+
+        let starts = (1..=4)
+            .map(|n| DayKind::Holiday.nth_next_start(&dt, n).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                dt,
+                Stockholm.ymd(2020, 12, 31).and_hms(0, 0, 0),
+                Stockholm.ymd(2021, 1, 3).and_hms(0, 0, 0),
+                Stockholm.ymd(2021, 1, 6).and_hms(0, 0, 0),
+            ],
+            starts
+        );
+
+        assert_eq!(None, DayKind::Weekend.nth_next_start(&dt, 1));
+
+        // Far ahead, but still representable.
+        let far = DayKind::Holiday.nth_next_start(&dt, 300_000).unwrap();
+        assert!(far.year() > 5000, "{}", far);
+    }
+
+    #[test]
+    fn test_max_kind_in_range() {
+        let monday = Stockholm.ymd(2020, 10, 19).and_hms(0, 0, 0);
+        let friday = Stockholm.ymd(2020, 10, 23).and_hms(0, 0, 0);
+        assert_eq!(DayKind::Weekday, max_kind_in_range(monday..friday));
+        assert_eq!(DayKind::Weekday, max_kind_in_range(monday..monday));
+
+        let sunday = Stockholm.ymd(2020, 10, 25).and_hms(0, 0, 0);
+        assert_eq!(DayKind::DayBeforeHoliday, max_kind_in_range(monday..sunday));
+        assert_eq!(
+            DayKind::Holiday,
+            max_kind_in_range(monday..sunday + Duration::seconds(1))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_prev_start_of_kind_never_returned() {
+        DayKind::Weekend.prev_start(&Stockholm.ymd(2020, 12, 26).and_hms(0, 0, 0));
+    }
+
+    #[test]
+    fn test_peek_does_not_consume() {
+        let start = Stockholm.ymd(2020, 9, 18).and_hms(0, 0, 0); // Friday
+        let end = Stockholm.ymd(2020, 9, 21).and_hms(0, 0, 0); // Monday
+
+        let mut iter = slice_on_day_kind(start..end);
+        let peeked = iter.peek().cloned();
+        assert_eq!(peeked, iter.next(), "Peeked slice should be the next one");
+        assert!(iter.peek().unwrap().matches_kind(DayKind::DayBeforeHoliday));
+        assert!(
+            iter.peek().unwrap().matches_kind(DayKind::DayBeforeHoliday),
+            "Peeking twice should not advance"
+        );
+
+        let mut peekable = iter.peekable();
+        assert_eq!(
+            Some(DayKind::DayBeforeHoliday),
+            peekable.peek().map(|slice| slice.kind),
+            "The lookahead should survive wrapping"
+        );
+        assert_eq!(2, peekable.count());
+    }
+
+    #[test]
+    fn test_duration_in_range() {
+        // Thursday 17:00 to Friday 09:00.
+        let start = Stockholm.ymd(2020, 9, 17).and_hms(17, 0, 0);
+        let end = Stockholm.ymd(2020, 9, 18).and_hms(9, 0, 0);
+        assert_eq!(
+            Duration::hours(16),
+            DayKind::Weekday.duration_in_range(start..end)
+        );
+        assert_eq!(
+            Duration::zero(),
+            DayKind::Holiday.duration_in_range(start..end)
+        );
+
+        // Starting mid Sunday, ending Tuesday midnight.
+        let start = Stockholm.ymd(2020, 9, 20).and_hms(12, 0, 0);
+        let end = Stockholm.ymd(2020, 9, 22).and_hms(0, 0, 0);
+        assert_eq!(
+            Duration::hours(12),
+            DayKind::Holiday.duration_in_range(start..end)
+        );
+        assert_eq!(
+            Duration::hours(24),
+            DayKind::Weekday.duration_in_range(start..end)
+        );
+    }
+
+    #[test]
+    fn test_duration_in_range_over_easter() {
+        let start = Stockholm.ymd(2020, 4, 8).and_hms(0, 0, 0); // Wed before good friday.
+        let end = Stockholm.ymd(2020, 4, 15).and_hms(0, 0, 0); // Wed after Easter.
+
+        // Good friday, Easter day and Annandag påsk.
+        assert_eq!(
+            Duration::days(3),
+            DayKind::Holiday.duration_in_range(start..end)
+        );
+        // Skärtorsdagen and the Saturday.
+        assert_eq!(
+            Duration::days(2),
+            DayKind::DayBeforeHoliday.duration_in_range(start..end)
+        );
+        // The two wednesdays.
+        assert_eq!(
+            Duration::days(2),
+            DayKind::Weekday.duration_in_range(start..end)
+        );
+    }
+
+    #[test]
+    fn test_matches_kind_ignores_range() {
+        let start = Stockholm.ymd(2020, 9, 20).and_hms(12, 0, 0); // Sunday
+        let end = Stockholm.ymd(2020, 9, 21).and_hms(0, 0, 0);
+        let slice = slice_on_day_kind(start..end).next().unwrap();
+
+        assert!(slice.matches_kind(DayKind::Holiday));
+        assert!(!slice.matches_kind(DayKind::Weekday));
+        assert!(!slice.matches_kind(DayKind::DayBeforeHoliday));
+    }
+
+    #[test]
+    fn test_slice_stream_from_range() {
+        let start = Stockholm.ymd(2020, 4, 8).and_hms(13, 37, 0);
+        let end = Stockholm.ymd(2020, 4, 15).and_hms(8, 0, 0);
+
+        let stream = DayKindSliceStream::from_range(start..end);
+        assert_eq!(6, stream.len());
+        assert_eq!(end - start, stream.total_duration());
+
+        // Extending with the continuation keeps it contiguous.
+        let later = end + Duration::days(2);
+        let mut extended = stream.clone();
+        extended.extend(slice_on_day_kind(end..later));
+        assert_eq!(later - start, extended.total_duration());
+
+        assert_eq!(
+            stream.into_iter().collect::<Vec<_>>(),
+            slice_on_day_kind(start..end).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_slice_stream_rejects_gaps() {
+        let start = Stockholm.ymd(2020, 4, 8).and_hms(0, 0, 0);
+        let mut stream = DayKindSliceStream::from_range(start..start + Duration::days(1));
+        stream.extend(slice_on_day_kind(
+            start + Duration::days(2)..start + Duration::days(3),
+        ));
+    }
+
+    #[test]
+    fn test_shift_slice_to_whole_days() {
+        let start = Stockholm.ymd(2020, 9, 17).and_hms(17, 0, 0);
+        let end = Stockholm.ymd(2020, 9, 18).and_hms(9, 0, 0);
+        let slice = slice_on_day_kind(start..end).next().unwrap();
+
+        let shifted = slice.shift_to_start_of_day();
+        assert_eq!(
+            Stockholm.ymd(2020, 9, 17).and_hms(0, 0, 0)..end,
+            shifted.range
+        );
+        assert_eq!(slice.kind, shifted.kind);
+
+        let shifted = slice.shift_to_end_of_day();
+        assert_eq!(
+            start..Stockholm.ymd(2020, 9, 19).and_hms(0, 0, 0),
+            shifted.range
+        );
+
+        let whole = slice.shift_to_start_of_day().shift_to_end_of_day();
+        assert!(whole.range.start < whole.range.end);
+        assert_eq!(Duration::days(2), whole.range.end - whole.range.start);
+
+        // Already aligned slices are left untouched.
+        assert_eq!(whole, whole.shift_to_start_of_day());
+        assert_eq!(whole, whole.shift_to_end_of_day());
+    }
+
+    #[test]
+    fn test_days_in_range_by_kind() {
+        let start = NaiveDate::from_ymd(2020, 3, 1);
+        let end = NaiveDate::from_ymd(2020, 4, 1);
+        let days = days_in_range_by_kind(start..end).collect::<Vec<_>>();
+
+        assert_eq!(31, days.len());
+        assert_eq!(start, days[0].0);
+        assert!(days.windows(2).all(|w| w[0].0.succ() == w[1].0));
+        for (date, kind) in days {
+            assert_eq!(day_kind(&date), kind, "{}", date);
+        }
+
+        assert_eq!(0, days_in_range_by_kind(end..start).count());
+    }
 }
@@ -0,0 +1,63 @@
+//! Easter related dates.
+//!
+//! Everything here is derived from Easter day (Påskdagen):
+//! Skärtorsdagen is 3 days before it, Långfredagen 2 days before it
+//! and Annandag påsk is the day after.
+
+use chrono::{Date, Duration, NaiveDate, TimeZone};
+use chrono_tz::{Europe::Stockholm, Tz};
+
+/// Påskdagen, Easter Sunday, of the Gregorian calendar.
+///
+/// Easter is the first Sunday after the Paschal full moon, the ecclesiastical full moon
+/// on or after the 21st of March. This is Oudin's algorithm (1940) in the form given by
+/// Claus Tøndering, computing it with integer arithmetic only. It always lands between
+/// the 22nd of March and the 25th of April.
+///
+/// Only valid for the Gregorian calendar, which Sweden has used since 1753.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::easter_sunday;
+///
+/// assert_eq!(NaiveDate::from_ymd(2024, 3, 31), easter_sunday(2024));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn easter_sunday(year: i32) -> NaiveDate {
+    // Position in the 19 year Metonic cycle of moon phases, the golden number minus 1.
+    let a = year % 19;
+    let b = year / 100;
+    // The epact, the age of the moon at the start of the year, corrected for the
+    // solar (b / 4) and lunar ((8b + 13) / 25) drift of the Gregorian calendar.
+    let c = (b - (b / 4) - ((8 * b + 13) / 25) + (19 * a) + 15) % 30;
+    // Days from the 21st of March to the Paschal full moon.
+    let d = c - (c / 28) * (1 - (c / 28) * (29 / (c + 1)) * ((21 - a) / 11));
+    // Days from the 21st of March to the Sunday on or before the Paschal full moon,
+    // -6 to 28. The weekday of the full moon is subtracted.
+    let e = d - ((year + (year / 4) + d + 2 - b + (b / 4)) % 7);
+    // Counting from the 28th of March, rolling over into April past the 31st.
+    let month = 3 + ((e + 40) / 44);
+    let day = e + 28 - (31 * (month / 4));
+
+    NaiveDate::from_ymd(year, month as u32, day as u32)
+}
+
+/// Same as `easter_sunday` but as a Stockholm date.
+pub fn easter_day_for_year(year: i32) -> Date<Tz> {
+    Stockholm.from_local_date(&easter_sunday(year)).unwrap()
+}
+
+/// Skärtorsdagen, the Thursday before Easter.
+/// Not a holiday itself, but always the day before one (Långfredagen).
+pub fn maundy_thursday(year: i32) -> NaiveDate {
+    easter_sunday(year) - Duration::days(3)
+}
+
+/// Långfredagen, the Friday before Easter.
+pub fn good_friday(year: i32) -> NaiveDate {
+    easter_sunday(year) - Duration::days(2)
+}
+
+/// Annandag påsk, the Monday after Easter.
+pub fn easter_monday(year: i32) -> NaiveDate {
+    easter_sunday(year) + Duration::days(1)
+}
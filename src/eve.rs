@@ -0,0 +1,286 @@
+use crate::{dates_in, day_kind, holiday_cluster_for, DayKind, Holiday};
+use chrono::{Datelike, NaiveDate};
+use std::fmt;
+
+/// The named eves, "aftnar", the days before holidays.
+///
+/// These are the days traditionally named after the holiday following them. An eve isn't
+/// necessarily a `DayKind::DayBeforeHoliday`: Julafton, Midsommarafton and Nyårsafton are
+/// holidays themselves and Valborgsmässoafton may fall on any weekday.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{Eve, Holiday};
+///
+/// let eve = Holiday::Pingstdagen.eve().unwrap();
+/// assert_eq!(Eve::Pingstafton, eve);
+/// assert_eq!(NaiveDate::from_ymd(2024, 5, 18), eve.date_in(2024));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Eve {
+    /// The day before Trettondedag jul, the 5th of January.
+    Trettondagsafton,
+    /// Maundy Thursday, the day before Långfredagen.
+    Skartorsdagen,
+    /// Holy Saturday, the day before Påskdagen.
+    Paskafton,
+    /// Walpurgis Night, the day before Första maj.
+    Valborgsmassoafton,
+    /// The day before Pingstdagen.
+    Pingstafton,
+    /// The day before Midsommardagen.
+    Midsommarafton,
+    /// The day before Alla helgons dag.
+    Allhelgonaafton,
+    /// The day before Juldagen.
+    Julafton,
+    /// The day before Nyårsdagen, in the year before it.
+    Nyarsafton,
+}
+
+impl Eve {
+    /// Every eve, in the order they occur during a year.
+    pub const ALL: [Eve; 9] = [
+        Self::Trettondagsafton,
+        Self::Skartorsdagen,
+        Self::Paskafton,
+        Self::Valborgsmassoafton,
+        Self::Pingstafton,
+        Self::Midsommarafton,
+        Self::Allhelgonaafton,
+        Self::Julafton,
+        Self::Nyarsafton,
+    ];
+
+    /// The holiday following the eve.
+    pub fn holiday(self) -> Holiday {
+        match self {
+            Self::Trettondagsafton => Holiday::TrettondedagJul,
+            Self::Skartorsdagen => Holiday::Langfredagen,
+            Self::Paskafton => Holiday::Paskdagen,
+            Self::Valborgsmassoafton => Holiday::ForstaMaj,
+            Self::Pingstafton => Holiday::Pingstdagen,
+            Self::Midsommarafton => Holiday::Midsommardagen,
+            Self::Allhelgonaafton => Holiday::AllaHelgonsDag,
+            Self::Julafton => Holiday::Juldagen,
+            Self::Nyarsafton => Holiday::Nyarsdagen,
+        }
+    }
+
+    /// Returns the date of the eve in year.
+    /// Nyårsafton is the 31st of December of year, not the day before Nyårsdagen of year.
+    pub fn date_in(self, year: i32) -> NaiveDate {
+        match self {
+            Self::Nyarsafton => Holiday::Nyarsafton.date_in(year),
+            _ => self.holiday().date_in(year).pred(),
+        }
+    }
+
+    /// Returns the eve falling on date, if any.
+    pub fn from_date(date: NaiveDate) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|eve| eve.date_in(date.year()) == date)
+    }
+
+    /// The English name of the eve. `Display` writes the Swedish one.
+    pub fn english_name(self) -> &'static str {
+        match self {
+            Self::Trettondagsafton => "Twelfth Night",
+            Self::Skartorsdagen => "Maundy Thursday",
+            Self::Paskafton => "Holy Saturday",
+            Self::Valborgsmassoafton => "Walpurgis Night",
+            Self::Pingstafton => "Whitsun Eve",
+            Self::Midsommarafton => "Midsummer Eve",
+            Self::Allhelgonaafton => "All Saints' Eve",
+            Self::Julafton => "Christmas Eve",
+            Self::Nyarsafton => "New Year's Eve",
+        }
+    }
+}
+
+impl fmt::Display for Eve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Trettondagsafton => "Trettondagsafton",
+            Self::Skartorsdagen => "Skärtorsdagen",
+            Self::Paskafton => "Påskafton",
+            Self::Valborgsmassoafton => "Valborgsmässoafton",
+            Self::Pingstafton => "Pingstafton",
+            Self::Midsommarafton => "Midsommarafton",
+            Self::Allhelgonaafton => "Allhelgonaafton",
+            Self::Julafton => "Julafton",
+            Self::Nyarsafton => "Nyårsafton",
+        })
+    }
+}
+
+/// Returns true for julafton, midsommarafton and nyårsafton.
+///
+/// Many Swedish collective agreements let employees leave at noon, or work reduced
+/// hours, on these eves. Other days before holidays, like Skärtorsdagen, are full days.
+/// This is an industry convention, not something decided by law.
+pub fn is_half_day_eve(date: NaiveDate) -> bool {
+    half_day_eves_in_year(date.year()).contains(&date)
+}
+
+/// Returns the dates of midsommarafton, julafton and nyårsafton, in that order.
+pub fn half_day_eves_in_year(year: i32) -> Vec<NaiveDate> {
+    vec![
+        Holiday::Midsommarafton.date_in(year),
+        Holiday::Julafton.date_in(year),
+        Holiday::Nyarsafton.date_in(year),
+    ]
+}
+
+/// Returns the eve of a holiday, the `DayKind::DayBeforeHoliday` right before it.
+///
+/// A holiday directly preceded by another holiday, like Annandag påsk, has no eve of its own
+/// and neither does a date that isn't a holiday. Both yield `None`.
+/// Note that the Saturday is the eve of Påskdagen, not Långfredagen.
+pub fn holiday_eve_for(holiday: NaiveDate) -> Option<NaiveDate> {
+    let eve = holiday.pred();
+    if day_kind(&holiday) == DayKind::Holiday && day_kind(&eve) == DayKind::DayBeforeHoliday {
+        Some(eve)
+    } else {
+        None
+    }
+}
+
+/// Returns the holiday following eve, if eve is a `DayKind::DayBeforeHoliday`.
+pub fn holiday_for_eve(eve: NaiveDate) -> Option<NaiveDate> {
+    if day_kind(&eve) == DayKind::DayBeforeHoliday {
+        Some(eve.succ())
+    } else {
+        None
+    }
+}
+
+/// Returns the dates of the break date is part of, from its leading eve through its last holiday.
+///
+/// A day before a holiday starts the chain itself. A holiday is given the whole break,
+/// including the eve leading into it. Working days yield an empty chain.
+pub fn holiday_eve_chain(date: NaiveDate) -> Vec<NaiveDate> {
+    let cluster = holiday_cluster_for(date);
+    let start = match day_kind(&date) {
+        DayKind::Weekday => return Vec::new(),
+        DayKind::DayBeforeHoliday | DayKind::HalfDay => date,
+        DayKind::Weekend | DayKind::Holiday => cluster.start,
+    };
+
+    dates_in(start..cluster.end).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{easter_monday, easter_sunday, good_friday, maundy_thursday};
+
+    #[test]
+    fn test_easter_eves_2020() {
+        assert_eq!(
+            Some(maundy_thursday(2020)),
+            holiday_eve_for(good_friday(2020))
+        );
+        assert_eq!(
+            Some(good_friday(2020)),
+            holiday_for_eve(maundy_thursday(2020))
+        );
+
+        // Påsklördagen.
+        let saturday = NaiveDate::from_ymd(2020, 4, 11);
+        assert_eq!(Some(saturday), holiday_eve_for(easter_sunday(2020)));
+        assert_eq!(Some(easter_sunday(2020)), holiday_for_eve(saturday));
+
+        // Directly after Påskdagen.
+        assert_eq!(None, holiday_eve_for(easter_monday(2020)));
+    }
+
+    #[test]
+    fn test_holiday_eve_chain() {
+        let d = |m, d| NaiveDate::from_ymd(2020, m, d);
+        let easter = vec![d(4, 9), d(4, 10), d(4, 11), d(4, 12), d(4, 13)];
+
+        assert_eq!(easter, holiday_eve_chain(maundy_thursday(2020)));
+        assert_eq!(easter, holiday_eve_chain(easter_sunday(2020)));
+        // Påsklördagen starts a chain of its own.
+        assert_eq!(easter[2..].to_vec(), holiday_eve_chain(d(4, 11)));
+
+        // Julafton is a Thursday, lead in by the Wednesday.
+        assert_eq!(
+            vec![d(12, 23), d(12, 24), d(12, 25), d(12, 26), d(12, 27)],
+            holiday_eve_chain(d(12, 24))
+        );
+
+        assert!(holiday_eve_chain(d(4, 14)).is_empty());
+    }
+
+    #[test]
+    fn test_eves_of_non_holidays() {
+        assert_eq!(None, holiday_eve_for(maundy_thursday(2020)));
+        assert_eq!(None, holiday_for_eve(good_friday(2020)));
+
+        // An ordinary Wednesday.
+        let wednesday = NaiveDate::from_ymd(2020, 9, 16);
+        assert_eq!(None, holiday_eve_for(wednesday));
+        assert_eq!(None, holiday_for_eve(wednesday));
+    }
+
+    #[test]
+    fn test_named_eves_2024() {
+        let d = |m, d| NaiveDate::from_ymd(2024, m, d);
+        let expected = [
+            d(1, 5),
+            d(3, 28),
+            d(3, 30),
+            d(4, 30),
+            d(5, 18),
+            d(6, 21),
+            d(11, 1),
+            d(12, 24),
+            d(12, 31),
+        ];
+
+        for (eve, date) in Eve::ALL.iter().zip(expected.iter()) {
+            assert_eq!(*date, eve.date_in(2024), "{}", eve);
+            assert_eq!(Some(*eve), Eve::from_date(*date));
+            assert_eq!(Some(*eve), eve.holiday().eve());
+        }
+
+        assert_eq!(None, Eve::from_date(d(6, 5)));
+        assert_eq!(None, Holiday::KristiHimmelfardsdag.eve());
+    }
+
+    #[test]
+    fn test_eves_that_are_holidays() {
+        for eve in &[Eve::Midsommarafton, Eve::Julafton, Eve::Nyarsafton] {
+            let date = eve.date_in(2024);
+            assert_eq!(
+                eve.to_string(),
+                crate::holiday_at(date).unwrap().to_string()
+            );
+            assert_eq!(DayKind::Holiday, day_kind(&date));
+        }
+    }
+
+    #[test]
+    fn test_half_day_eves_2020() {
+        let eves = half_day_eves_in_year(2020);
+        assert_eq!(
+            vec![
+                NaiveDate::from_ymd(2020, 6, 19),
+                NaiveDate::from_ymd(2020, 12, 24),
+                NaiveDate::from_ymd(2020, 12, 31),
+            ],
+            eves
+        );
+
+        for eve in eves {
+            assert!(is_half_day_eve(eve), "{}", eve);
+        }
+
+        assert!(!is_half_day_eve(maundy_thursday(2020)));
+        assert!(!is_half_day_eve(NaiveDate::from_ymd(2020, 6, 20)));
+        assert!(!is_half_day_eve(NaiveDate::from_ymd(2020, 12, 23)));
+    }
+}
@@ -0,0 +1,63 @@
+//! Holidays on a given weekday within a window of dates.
+//!
+//! Midsommardagen is the Saturday between the 20th and 26th of June, with Midsommarafton
+//! the day before it. Alla helgons dag is the Saturday between the 31st of October and
+//! the 6th of November.
+
+use crate::closest_next;
+use chrono::{NaiveDate, Weekday};
+
+/// Midsommarafton, the Friday between the 19th and 25th of June.
+pub fn midsummer_eve(year: i32) -> NaiveDate {
+    midsummer_day(year).pred()
+}
+
+/// Midsommardagen, the Saturday between the 20th and 26th of June.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::midsummer_day;
+///
+/// assert_eq!(NaiveDate::from_ymd(2024, 6, 22), midsummer_day(2024));
+/// ```
+pub fn midsummer_day(year: i32) -> NaiveDate {
+    closest_next(NaiveDate::from_ymd(year, 6, 20), Weekday::Sat)
+}
+
+/// Alla helgons dag, the Saturday between the 31st of October and 6th of November.
+pub fn all_saints_day(year: i32) -> NaiveDate {
+    closest_next(NaiveDate::from_ymd(year, 10, 31), Weekday::Sat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    #[test]
+    fn test_floating_dates() {
+        // The 20th of June 2020 is a Saturday itself.
+        assert_eq!(NaiveDate::from_ymd(2020, 6, 20), midsummer_day(2020));
+        assert_eq!(NaiveDate::from_ymd(2020, 6, 19), midsummer_eve(2020));
+        assert_eq!(NaiveDate::from_ymd(2021, 6, 26), midsummer_day(2021));
+
+        assert_eq!(NaiveDate::from_ymd(2020, 10, 31), all_saints_day(2020));
+        assert_eq!(NaiveDate::from_ymd(2021, 11, 6), all_saints_day(2021));
+
+        for year in 1900..2100 {
+            let midsummer = midsummer_day(year);
+            assert_eq!(Weekday::Sat, midsummer.weekday());
+            assert_eq!(6, midsummer.month());
+            assert!((20..=26).contains(&midsummer.day()), "{}", midsummer);
+            assert_eq!(Weekday::Fri, midsummer_eve(year).weekday());
+
+            let all_saints = all_saints_day(year);
+            assert_eq!(Weekday::Sat, all_saints.weekday());
+            assert!(
+                NaiveDate::from_ymd(year, 10, 31) <= all_saints
+                    && all_saints <= NaiveDate::from_ymd(year, 11, 6),
+                "{}",
+                all_saints
+            );
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{dates_in, holiday_at, holidays, DayKind, DayKindSlice, Holiday};
+use chrono::{Datelike, Duration, NaiveDate, Timelike};
+use std::{collections::BTreeMap, error, fmt, fmt::Write};
+
+/// Formats the length of d as hours and minutes, e.g. "7h 30min".
+/// The sign is dropped, see `format_duration_hm_signed`. Seconds are truncated.
+pub fn format_duration_as_hours_minutes(d: Duration) -> String {
+    let minutes = d.num_minutes().abs();
+    format!("{}h {}min", minutes / 60, minutes % 60)
+}
+
+/// Same as `format_duration_as_hours_minutes` but prefixed with "-" for negative durations.
+pub fn format_duration_hm_signed(d: Duration) -> String {
+    if d.num_minutes() < 0 {
+        format!("-{}", format_duration_as_hours_minutes(d))
+    } else {
+        format_duration_as_hours_minutes(d)
+    }
+}
+
+/// Formats d as decimal hours, e.g. "7.50" with 2 decimals.
+pub fn format_duration_decimal_hours(d: Duration, decimals: usize) -> String {
+    let hours = d.num_seconds() as f64 / 3600.0;
+    format!("{:.*}", decimals, hours)
+}
+
+/// Parses durations on the form written by `format_duration_hm_signed`.
+/// Either of the hour and minute parts may be left out, "7h", "30min" and "-1h 15min" are all valid.
+pub fn parse_hours_minutes(s: &str) -> Result<Duration, ParseDurationError> {
+    let err = || ParseDurationError(s.to_string());
+
+    let trimmed = s.trim();
+    let (negative, rest) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed),
+    };
+
+    let mut hours = None;
+    let mut minutes = None;
+
+    for part in rest.split_whitespace() {
+        if let Some(m) = part.strip_suffix("min") {
+            if minutes.is_some() {
+                return Err(err());
+            }
+            minutes = Some(m.parse::<u32>().map_err(|_| err())?);
+        } else if let Some(h) = part.strip_suffix('h') {
+            // Hours must come first.
+            if hours.is_some() || minutes.is_some() {
+                return Err(err());
+            }
+            hours = Some(h.parse::<u32>().map_err(|_| err())?);
+        } else {
+            return Err(err());
+        }
+    }
+
+    let total = match (hours, minutes) {
+        (None, None) => return Err(err()),
+        (Some(_), Some(m)) if m >= 60 => return Err(err()),
+        (h, m) => Duration::hours(h.unwrap_or(0).into()) + Duration::minutes(m.unwrap_or(0).into()),
+    };
+
+    if negative {
+        Ok(-total)
+    } else {
+        Ok(total)
+    }
+}
+
+/// The input to `parse_hours_minutes` was not a valid duration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDurationError(String);
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid hours and minutes duration: {:?}", self.0)
+    }
+}
+
+impl error::Error for ParseDurationError {}
+
+/// Formats a holiday as "YYYY-MM-DD Name", e.g. "2024-03-29 Långfredagen".
+pub fn format_holiday_date(date: NaiveDate, holiday: Holiday) -> String {
+    format!("{} {}", date.format("%Y-%m-%d"), holiday)
+}
+
+/// Parses a holiday on the form "YYYY-MM-DD Name", as written by `format_holiday_date`,
+/// or just "YYYY-MM-DD" in which case the holiday is looked up by its date.
+/// The name is matched case insensitively.
+pub fn parse_holiday_date(s: &str) -> Result<(NaiveDate, Holiday), ParseHolidayError> {
+    let s = s.trim();
+    let (date_part, name_part) = match s.find(char::is_whitespace) {
+        Some(i) => (&s[..i], Some(s[i..].trim())),
+        None => (s, None),
+    };
+
+    let date = NaiveDate::parse_from_str(date_part, "%Y-%m-%d")
+        .map_err(|_| ParseHolidayError::InvalidDate(date_part.to_string()))?;
+
+    let holiday = match name_part {
+        Some(name) => {
+            let lowercase = name.to_lowercase();
+            let holiday = holidays()
+                .find(|h| h.to_string().to_lowercase() == lowercase)
+                .ok_or_else(|| ParseHolidayError::InvalidHolidayName(name.to_string()))?;
+
+            if !holiday.is_holiday_in(date.year()) || holiday.date_in(date.year()) != date {
+                return Err(ParseHolidayError::DateNotAHoliday(date));
+            }
+            holiday
+        }
+
+        None => holiday_at(date).ok_or(ParseHolidayError::DateNotAHoliday(date))?,
+    };
+
+    Ok((date, holiday))
+}
+
+/// The input to `parse_holiday_date` was not a valid holiday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseHolidayError {
+    /// The date was not on the form YYYY-MM-DD.
+    InvalidDate(String),
+    /// The name is not the Swedish name of any holiday.
+    InvalidHolidayName(String),
+    /// The date is not a holiday, or not the named one.
+    DateNotAHoliday(NaiveDate),
+}
+
+impl fmt::Display for ParseHolidayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidDate(s) => write!(f, "invalid date: {:?}", s),
+            Self::InvalidHolidayName(s) => write!(f, "unknown holiday: {:?}", s),
+            Self::DateNotAHoliday(date) => write!(f, "{} is not the given holiday", date),
+        }
+    }
+}
+
+impl error::Error for ParseHolidayError {}
+
+/// Formats slices as a fixed width table, for debugging.
+///
+/// Each slice is a row, ordered by start time, with the English names of any holidays within it.
+/// The total duration of each kind is listed below the table.
+pub fn format_day_kind_report(slices: &[DayKindSlice]) -> String {
+    let mut sorted = slices.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|s| s.range.start);
+
+    let mut report = format!(
+        "{:<17} {:<17} {:<12} {:<17} {}\n",
+        "Start", "End", "Duration", "Kind", "Holiday Name"
+    );
+
+    let mut totals = BTreeMap::<DayKind, Duration>::new();
+    for slice in sorted {
+        let duration = slice.range.end - slice.range.start;
+        let total = totals.entry(slice.kind).or_insert_with(Duration::zero);
+        *total += duration;
+
+        // Every date the slice touches, including a partial last day.
+        let first = slice.range.start.naive_local().date();
+        let mut end = slice.range.end.naive_local().date();
+        if slice.range.end.num_seconds_from_midnight() != 0 {
+            end = end.succ();
+        }
+        let names = dates_in(first..end)
+            .filter_map(holiday_at)
+            .map(Holiday::english_name)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let _ = writeln!(
+            report,
+            "{:<17} {:<17} {:<12} {:<17} {}",
+            slice.range.start.format("%Y-%m-%d %H:%M").to_string(),
+            slice.range.end.format("%Y-%m-%d %H:%M").to_string(),
+            format_duration_as_hours_minutes(duration),
+            slice.kind.to_string(),
+            names
+        );
+    }
+
+    report.push_str("\nTotals\n");
+    for (kind, total) in totals {
+        let _ = writeln!(
+            report,
+            "{:<17} {}",
+            kind.to_string(),
+            format_duration_as_hours_minutes(total)
+        );
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slice_on_day_kind;
+    use chrono::TimeZone;
+    use chrono_tz::Europe::Stockholm;
+
+    #[test]
+    fn test_day_kind_report_easter_2020() {
+        // Monday before Easter through the Sunday after.
+        let start = Stockholm.ymd(2020, 4, 6).and_hms(0, 0, 0);
+        let end = Stockholm.ymd(2020, 4, 20).and_hms(0, 0, 0);
+        let slices = slice_on_day_kind(start..end).collect::<Vec<_>>();
+
+        let report = format_day_kind_report(&slices);
+        assert!(report.starts_with("Start "));
+        assert!(report.contains("Good Friday"));
+        assert!(report.contains("Easter Sunday, Easter Monday"));
+
+        // Långfredagen, Påskdagen, Annandag påsk and the next Sunday.
+        assert!(
+            report.contains("\nholiday           96h 0min\n"),
+            "{}",
+            report
+        );
+    }
+
+    #[test]
+    fn test_empty_day_kind_report() {
+        let report = format_day_kind_report(&[]);
+        assert_eq!(2, report.lines().filter(|l| !l.is_empty()).count());
+    }
+
+    #[test]
+    fn test_format_hours_minutes() {
+        let cases = vec![
+            (Duration::minutes(7 * 60 + 30), "7h 30min", "7.50"),
+            (Duration::minutes(30), "0h 30min", "0.50"),
+            (-Duration::minutes(75), "-1h 15min", "-1.25"),
+            (Duration::zero(), "0h 0min", "0.00"),
+            (Duration::hours(24), "24h 0min", "24.00"),
+        ];
+
+        for (d, hm, decimal) in cases {
+            assert_eq!(hm, format_duration_hm_signed(d));
+            assert_eq!(decimal, format_duration_decimal_hours(d, 2));
+            assert_eq!(Ok(d), parse_hours_minutes(hm), "Should round trip {}", hm);
+        }
+
+        assert_eq!(
+            "1h 15min",
+            format_duration_as_hours_minutes(-Duration::minutes(75))
+        );
+        assert_eq!("8", format_duration_decimal_hours(Duration::hours(8), 0));
+    }
+
+    #[test]
+    fn test_parse_partial_hours_minutes() {
+        assert_eq!(Ok(Duration::hours(7)), parse_hours_minutes("7h"));
+        assert_eq!(Ok(Duration::minutes(90)), parse_hours_minutes("90min"));
+        assert_eq!(Ok(-Duration::minutes(30)), parse_hours_minutes(" -30min "));
+    }
+
+    #[test]
+    fn test_parse_invalid_hours_minutes() {
+        for s in &[
+            "", "-", "7", "7.5h", "30min 7h", "1h 60min", "7h 7h", "h", "1h -5min",
+        ] {
+            assert!(parse_hours_minutes(s).is_err(), "{:?} should not parse", s);
+        }
+    }
+
+    #[test]
+    fn test_holiday_date_round_trip() {
+        for (date, holiday) in crate::holidays_in_year(2024) {
+            let formatted = format_holiday_date(date, holiday);
+            assert_eq!(Ok((date, holiday)), parse_holiday_date(&formatted));
+
+            let date_only = date.format("%Y-%m-%d").to_string();
+            assert_eq!(Ok((date, holiday)), parse_holiday_date(&date_only));
+        }
+    }
+
+    #[test]
+    fn test_parse_holiday_date() {
+        assert_eq!(
+            Ok((
+                NaiveDate::from_ymd(2024, 5, 9),
+                Holiday::KristiHimmelfardsdag
+            )),
+            parse_holiday_date("2024-05-09 kristi HIMMELSFÄRDSDAG")
+        );
+        assert_eq!(
+            Err(ParseHolidayError::InvalidDate("2024-13-01".to_string())),
+            parse_holiday_date("2024-13-01 Juldagen")
+        );
+        assert_eq!(
+            Err(ParseHolidayError::InvalidHolidayName("Valborg".to_string())),
+            parse_holiday_date("2024-04-30 Valborg")
+        );
+        assert_eq!(
+            Err(ParseHolidayError::DateNotAHoliday(NaiveDate::from_ymd(
+                2024, 12, 24
+            ))),
+            parse_holiday_date("2024-12-24 Juldagen")
+        );
+        assert_eq!(
+            Err(ParseHolidayError::DateNotAHoliday(NaiveDate::from_ymd(
+                2024, 4, 30
+            ))),
+            parse_holiday_date("2024-04-30")
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{slice_on_day_kind, DayKind};
+use chrono::{DateTime, Duration};
+use chrono_tz::Tz;
+
+/// Hours worked, split by the kind of day they were worked on.
+/// The three kinds always add up to `total_hours`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WorkingHoursReport {
+    pub weekday_hours: f64,
+    pub pre_holiday_hours: f64,
+    pub holiday_hours: f64,
+    pub total_hours: f64,
+}
+
+/// Sums up the hours of events by `DayKind`.
+///
+/// Every event is counted on its own, overlapping events are counted more than once.
+pub fn compute_working_hours_report(events: &[(DateTime<Tz>, DateTime<Tz>)]) -> WorkingHoursReport {
+    let mut weekday = Duration::zero();
+    let mut pre_holiday = Duration::zero();
+    let mut holiday = Duration::zero();
+
+    for (start, end) in events {
+        for slice in slice_on_day_kind(*start..*end) {
+            let duration = slice.range.end - slice.range.start;
+            match slice.kind {
+                DayKind::Weekday => weekday += duration,
+                DayKind::DayBeforeHoliday | DayKind::HalfDay => pre_holiday += duration,
+                DayKind::Weekend | DayKind::Holiday => holiday += duration,
+            }
+        }
+    }
+
+    let hours = |d: Duration| d.num_seconds() as f64 / 3600.0;
+    WorkingHoursReport {
+        weekday_hours: hours(weekday),
+        pre_holiday_hours: hours(pre_holiday),
+        holiday_hours: hours(holiday),
+        total_hours: hours(weekday + pre_holiday + holiday),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use chrono_tz::Europe::Stockholm;
+
+    fn event(y: i32, m: u32, d: u32, from: u32, to: u32) -> (DateTime<Tz>, DateTime<Tz>) {
+        let date = Stockholm.ymd(y, m, d);
+        (date.and_hms(from, 0, 0), date.and_hms(to, 0, 0))
+    }
+
+    #[test]
+    fn test_working_hours_report() {
+        let mut events = (14..=18)
+            .map(|d| event(2020, 9, d, 8, 17))
+            .collect::<Vec<_>>();
+
+        // Skärtorsdagen and Långfredagen.
+        events.push(event(2020, 4, 9, 8, 17));
+        events.push(event(2020, 4, 10, 8, 12));
+        // Overnight into Långfredagen.
+        events.push((
+            Stockholm.ymd(2020, 4, 9).and_hms(22, 0, 0),
+            Stockholm.ymd(2020, 4, 10).and_hms(2, 0, 0),
+        ));
+
+        let report = compute_working_hours_report(&events);
+        assert_eq!(45.0, report.weekday_hours);
+        assert_eq!(11.0, report.pre_holiday_hours);
+        assert_eq!(6.0, report.holiday_hours);
+        assert_eq!(62.0, report.total_hours);
+        assert_eq!(
+            report.total_hours,
+            report.weekday_hours + report.pre_holiday_hours + report.holiday_hours
+        );
+    }
+
+    #[test]
+    fn test_overlapping_events_are_counted_twice() {
+        let events = [event(2020, 9, 14, 8, 17), event(2020, 9, 14, 12, 13)];
+        assert_eq!(10.0, compute_working_hours_report(&events).weekday_hours);
+
+        assert_eq!(
+            WorkingHoursReport::default(),
+            compute_working_hours_report(&[])
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{holiday_at, DayKind};
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::{iter::FromIterator, ops::BitOr};
+
+/// A set of `DayKind`s, see `day_kinds`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DayKindSet(u8);
+
+const KINDS: [DayKind; 5] = [
+    DayKind::Weekday,
+    DayKind::DayBeforeHoliday,
+    DayKind::HalfDay,
+    DayKind::Weekend,
+    DayKind::Holiday,
+];
+
+impl DayKindSet {
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, kind: DayKind) -> bool {
+        self.0 & bit(kind) != 0
+    }
+
+    pub fn insert(&mut self, kind: DayKind) {
+        self.0 |= bit(kind);
+    }
+
+    /// Returns the kinds of the set in `Ord` order.
+    pub fn iter(self) -> impl Iterator<Item = DayKind> {
+        KINDS
+            .iter()
+            .copied()
+            .filter(move |kind| self.contains(*kind))
+    }
+
+    /// Returns the greatest kind of the set, the one `day_kind` settles on.
+    pub fn max(self) -> Option<DayKind> {
+        self.iter().last()
+    }
+}
+
+fn bit(kind: DayKind) -> u8 {
+    1 << kind as u8
+}
+
+impl From<DayKind> for DayKindSet {
+    fn from(kind: DayKind) -> Self {
+        Self(bit(kind))
+    }
+}
+
+impl FromIterator<DayKind> for DayKindSet {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = DayKind>,
+    {
+        let mut set = Self::empty();
+        for kind in iter {
+            set.insert(kind);
+        }
+        set
+    }
+}
+
+/// The union of the two sets.
+impl BitOr for DayKindSet {
+    type Output = DayKindSet;
+
+    fn bitor(self, rhs: DayKindSet) -> DayKindSet {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Returns every kind that applies to d, instead of only the one `day_kind` settles on.
+///
+/// - `Weekday` for Mondays through Fridays.
+/// - `DayBeforeHoliday` for Saturdays and days before a named holiday or a Sunday.
+/// - `Holiday` for named holidays and Sundays.
+///
+/// The greatest of them, `DayKindSet::max`, is the same as `day_kind`.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{day_kinds, DayKind};
+///
+/// // Juldagen, also the day before Annandag jul.
+/// let kinds = day_kinds(&NaiveDate::from_ymd(2024, 12, 25));
+/// assert_eq!(
+///     vec![DayKind::Weekday, DayKind::DayBeforeHoliday, DayKind::Holiday],
+///     kinds.iter().collect::<Vec<_>>()
+/// );
+/// ```
+pub fn day_kinds<D>(d: &D) -> DayKindSet
+where
+    D: Datelike,
+{
+    let date = NaiveDate::from_yo(d.year(), d.ordinal());
+    let is_holiday = |d: NaiveDate| d.weekday() == Weekday::Sun || holiday_at(d).is_some();
+
+    let mut set = DayKindSet::empty();
+    if date.weekday() != Weekday::Sat && date.weekday() != Weekday::Sun {
+        set.insert(DayKind::Weekday);
+    }
+    if date.weekday() == Weekday::Sat || is_holiday(date.succ()) {
+        set.insert(DayKind::DayBeforeHoliday);
+    }
+    if is_holiday(date) {
+        set.insert(DayKind::Holiday);
+    }
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dates_in, day_kind};
+
+    #[test]
+    fn test_saturday_national_day() {
+        let kinds = day_kinds(&NaiveDate::from_ymd(2020, 6, 6));
+        assert_eq!(
+            DayKindSet::from(DayKind::DayBeforeHoliday) | DayKindSet::from(DayKind::Holiday),
+            kinds
+        );
+        assert!(!kinds.contains(DayKind::Weekday));
+    }
+
+    #[test]
+    fn test_max_is_day_kind() {
+        let start = NaiveDate::from_ymd(2024, 1, 1);
+        let end = NaiveDate::from_ymd(2025, 1, 1);
+        for date in dates_in(start..end) {
+            assert_eq!(Some(day_kind(&date)), day_kinds(&date).max(), "{}", date);
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut set = DayKindSet::empty();
+        assert!(set.is_empty());
+        assert_eq!(None, set.max());
+
+        set.insert(DayKind::Holiday);
+        set.insert(DayKind::Weekday);
+        let collected: DayKindSet = vec![DayKind::Holiday, DayKind::Weekday]
+            .into_iter()
+            .collect();
+        assert_eq!(collected, set);
+        assert_eq!(
+            vec![DayKind::Weekday, DayKind::Holiday],
+            set.iter().collect::<Vec<_>>()
+        );
+    }
+}
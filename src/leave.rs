@@ -0,0 +1,450 @@
+use crate::{
+    dates_in, day_kind, days_between, slice_on_day_kind_dates, with_sorted_holidays,
+    working_days_between, DayKind, Holiday,
+};
+use chrono::{Datelike, IsoWeek, NaiveDate, Weekday};
+use std::ops::Range;
+
+/// Everything needed to plan leave during a year.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VacationCalendar {
+    pub year: i32,
+    /// Number of `DayKind::Weekday` days in the year.
+    pub working_days: u32,
+    /// All holidays of the year, ordered by date.
+    pub holidays: Vec<(NaiveDate, Holiday)>,
+    /// See `is_bridge_day`.
+    pub bridge_days: Vec<NaiveDate>,
+    /// Every stretch of 3 or more consecutive non-working days.
+    pub long_weekends: Vec<Range<NaiveDate>>,
+}
+
+impl VacationCalendar {
+    pub fn for_year(year: i32) -> Self {
+        let start = NaiveDate::from_ymd(year, 1, 1);
+        let end = NaiveDate::from_ymd(year + 1, 1, 1);
+
+        let bridge_days = dates_in(start..end).filter(is_bridge_day).collect();
+
+        let mut long_weekends = Vec::new();
+        let mut off_since = None;
+        for date in dates_in(start..end.succ()) {
+            match (
+                off_since,
+                day_kind(&date) == DayKind::Weekday || date == end,
+            ) {
+                (None, false) => off_since = Some(date),
+                (Some(since), true) => {
+                    if days_between(since, date) >= 3 {
+                        long_weekends.push(since..date);
+                    }
+                    off_since = None;
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            year,
+            working_days: working_days_between(start, end),
+            holidays: with_sorted_holidays(year, <[_]>::to_vec),
+            bridge_days,
+            long_weekends,
+        }
+    }
+
+    /// Returns the n ISO weeks of the year with the most non-working days.
+    /// Each week comes with its number of non-working days and the number
+    /// of vacation days needed to take the whole week off.
+    /// Weeks with equally many days off are ordered by week number.
+    pub fn best_leave_weeks(&self, n: usize) -> Vec<(IsoWeek, u32, u32)> {
+        let mut weeks = (1..)
+            .map(|week| NaiveDate::from_isoywd_opt(self.year, week, Weekday::Mon))
+            .take_while(Option::is_some)
+            .flatten()
+            .map(|monday| {
+                let needed = working_days_between(monday, monday + chrono::Duration::weeks(1));
+                (monday.iso_week(), 7 - needed, needed)
+            })
+            .collect::<Vec<_>>();
+
+        weeks.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.week().cmp(&b.0.week())));
+        weeks.truncate(n);
+        weeks
+    }
+}
+
+/// Returns true if date is a working day squeezed in between a holiday
+/// and another non-working day. Like the Friday after Kristi himmelsfärdsdag.
+pub fn is_bridge_day(date: &NaiveDate) -> bool {
+    day_kind(date) == DayKind::Weekday
+        && day_kind(&date.pred()) == DayKind::Holiday
+        && day_kind(&date.succ()) != DayKind::Weekday
+}
+
+/// Heuristic for a "klämdag", a working day most people take off since it's squeezed in
+/// between days off. Not a legal definition.
+///
+/// Unlike `is_bridge_day`, this also covers days before holidays that fall on a weekday,
+/// like the Monday before a holiday on a Tuesday. They're ordinary working days to most.
+/// Days off are holidays, Sundays included, and Saturdays.
+///
+/// Also available as `ClassifiedDay::klamdag` from `classify`.
+pub fn is_klamdag_recommended(date: NaiveDate) -> bool {
+    let is_working = |d: &NaiveDate| match day_kind(d) {
+        DayKind::Weekday | DayKind::HalfDay => true,
+        DayKind::DayBeforeHoliday => d.weekday() != Weekday::Sat,
+        DayKind::Weekend | DayKind::Holiday => false,
+    };
+
+    let is_off = |d: &NaiveDate| !is_working(d);
+
+    is_working(&date) && is_off(&date.pred()) && is_off(&date.succ())
+}
+
+/// Returns the whole stretch of consecutive non-working days that date is part of.
+/// Like Långfredagen through Annandag påsk.
+///
+/// If date is a working day, the range is empty and starts at date.
+pub fn holiday_cluster_for(date: NaiveDate) -> Range<NaiveDate> {
+    let is_off = |d: &NaiveDate| day_kind(d) != DayKind::Weekday;
+    if !is_off(&date) {
+        return date..date;
+    }
+
+    let mut start = date;
+    while is_off(&start.pred()) {
+        start = start.pred();
+    }
+
+    let mut end = date.succ();
+    while is_off(&end) {
+        end = end.succ();
+    }
+
+    start..end
+}
+
+/// Returns true if a and b, in any order, are non-working days within the same
+/// holiday cluster. See `holiday_cluster_for`.
+pub fn are_same_holiday_cluster(a: NaiveDate, b: NaiveDate) -> bool {
+    let (first, last) = if a <= b { (a, b) } else { (b, a) };
+    dates_in(first..last.succ()).all(|d| day_kind(&d) != DayKind::Weekday)
+}
+
+/// Groups the `DayKind::Holiday` dates of range into runs of consecutive days.
+/// Any other kind of day, including days before holidays, breaks a run.
+pub fn consecutive_holiday_groups_in_range(range: Range<NaiveDate>) -> Vec<Vec<NaiveDate>> {
+    slice_on_day_kind_dates(range)
+        .filter(|slice| slice.kind == DayKind::Holiday)
+        .map(|slice| dates_in(slice.range).collect())
+        .collect()
+}
+
+/// Returns the number of röda dagar falling on Monday through Friday during the
+/// employment year starting at start, that is up until the same date the year after.
+///
+/// The de facto holidays Julafton, Midsommarafton and Nyårsafton aren't counted,
+/// see `Holiday::is_red_day`.
+/// An employment year starting on the 29th of February ends on the 1st of March.
+pub fn statutory_holiday_days_in_employment_year(start: NaiveDate) -> u32 {
+    let end = NaiveDate::from_ymd_opt(start.year() + 1, start.month(), start.day())
+        .unwrap_or_else(|| NaiveDate::from_ymd(start.year() + 1, 3, 1));
+
+    crate::upcoming_holidays(start)
+        .take_while(|(d, _)| *d < end)
+        .filter(|(_, holiday)| holiday.is_red_day())
+        .filter(|(d, _)| d.weekday().num_days_from_monday() < 5)
+        .count() as u32
+}
+
+/// Returns the fraction of days in range that are `DayKind::Holiday`.
+/// 0.0 means no holidays at all, 1.0 means every day is a holiday.
+/// An empty range has a density of 0.0.
+pub fn holiday_density(range: Range<NaiveDate>) -> f64 {
+    density(range, |kind| kind == DayKind::Holiday)
+}
+
+/// Returns the fraction of days in range that are either `DayKind::Holiday`
+/// or `DayKind::DayBeforeHoliday`.
+/// An empty range has a density of 0.0.
+pub fn non_working_density(range: Range<NaiveDate>) -> f64 {
+    density(range, |kind| kind != DayKind::Weekday)
+}
+
+/// Returns how many non-working days come for free per working day taken off,
+/// if the whole range is taken off.
+///
+/// A range without any working days is infinitely efficient, an empty range yields 0.0.
+pub fn vacation_efficiency(range: Range<NaiveDate>) -> f64 {
+    let (working, non_working) = dates_in(range).fold((0u32, 0u32), |(w, n), d| {
+        if day_kind(&d) == DayKind::Weekday {
+            (w + 1, n)
+        } else {
+            (w, n + 1)
+        }
+    });
+
+    if working + non_working == 0 {
+        return 0.0;
+    }
+
+    f64::from(non_working) / f64::from(working)
+}
+
+/// Returns the number of vacation days an absence from `from` through `to`, inclusive, costs.
+///
+/// Under semesterlagen and the standard collective agreements, only ordinary working days
+/// consume vacation days. Holidays and days before holidays (including Saturdays) are free.
+pub fn count_vacation_days_needed(from: NaiveDate, to: NaiveDate) -> u32 {
+    working_days_between(from, to.succ())
+}
+
+fn density<F>(range: Range<NaiveDate>, pred: F) -> f64
+where
+    F: Fn(DayKind) -> bool,
+{
+    let (total, matching) = dates_in(range).fold((0u32, 0u32), |(t, m), d| {
+        if pred(day_kind(&d)) {
+            (t + 1, m + 1)
+        } else {
+            (t + 1, m)
+        }
+    });
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    f64::from(matching) / f64::from(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn easter_week_2020() -> Range<NaiveDate> {
+        NaiveDate::from_ymd(2020, 4, 6)..NaiveDate::from_ymd(2020, 4, 13)
+    }
+
+    fn normal_week_2020() -> Range<NaiveDate> {
+        NaiveDate::from_ymd(2020, 9, 14)..NaiveDate::from_ymd(2020, 9, 21)
+    }
+
+    #[test]
+    fn test_holiday_density() {
+        // Good friday and Easter day.
+        assert_eq!(2.0 / 7.0, holiday_density(easter_week_2020()));
+        // Only the Sunday.
+        assert_eq!(1.0 / 7.0, holiday_density(normal_week_2020()));
+        assert_eq!(
+            0.0,
+            holiday_density(NaiveDate::from_ymd(2020, 4, 6)..NaiveDate::from_ymd(2020, 4, 6))
+        );
+    }
+
+    #[test]
+    fn test_non_working_density() {
+        // Skärtorsdagen and the Saturday are days before holidays, making 4 of 7 days off.
+        assert_eq!(4.0 / 7.0, non_working_density(easter_week_2020()));
+        assert_eq!(2.0 / 7.0, non_working_density(normal_week_2020()));
+    }
+
+    #[test]
+    fn test_vacation_efficiency() {
+        assert_eq!(4.0 / 3.0, vacation_efficiency(easter_week_2020()));
+        assert_eq!(2.0 / 5.0, vacation_efficiency(normal_week_2020()));
+
+        // A weekend only, costs nothing.
+        assert_eq!(
+            f64::INFINITY,
+            vacation_efficiency(NaiveDate::from_ymd(2020, 9, 19)..NaiveDate::from_ymd(2020, 9, 21))
+        );
+    }
+
+    #[test]
+    fn test_easter_beats_normal_week() {
+        assert!(holiday_density(easter_week_2020()) > holiday_density(normal_week_2020()));
+        assert!(non_working_density(easter_week_2020()) > non_working_density(normal_week_2020()));
+        assert!(vacation_efficiency(easter_week_2020()) > vacation_efficiency(normal_week_2020()));
+    }
+
+    #[test]
+    fn test_count_vacation_days_needed() {
+        // Monday before Easter through Sunday the week after.
+        // Skärtorsdagen, Långfredagen, Annandag påsk and the weekends are free,
+        // leaving Mon-Wed the first week and Tue-Fri the second.
+        assert_eq!(
+            7,
+            count_vacation_days_needed(
+                NaiveDate::from_ymd(2020, 4, 6),
+                NaiveDate::from_ymd(2020, 4, 19)
+            )
+        );
+
+        // A single working day costs a day.
+        let monday = NaiveDate::from_ymd(2020, 9, 14);
+        assert_eq!(1, count_vacation_days_needed(monday, monday));
+    }
+
+    #[test]
+    fn test_bridge_days() {
+        // Friday after Kristi himmelsfärdsdag.
+        assert!(is_bridge_day(&NaiveDate::from_ymd(2024, 5, 10)));
+        // The Thursday itself, and an ordinary friday.
+        assert!(!is_bridge_day(&NaiveDate::from_ymd(2024, 5, 9)));
+        assert!(!is_bridge_day(&NaiveDate::from_ymd(2024, 5, 17)));
+    }
+
+    #[test]
+    fn test_klamdag_around_ascension_day() {
+        for year in 2018..=2030 {
+            let ascension_day = Holiday::KristiHimmelfardsdag.date_in(year);
+            let friday = ascension_day.succ();
+            let tuesday = ascension_day.pred().pred();
+
+            assert!(is_klamdag_recommended(friday), "{}", friday);
+            assert!(!is_klamdag_recommended(tuesday), "{}", tuesday);
+            assert!(!is_klamdag_recommended(ascension_day), "{}", ascension_day);
+        }
+    }
+
+    #[test]
+    fn test_klamdag_before_tuesday_holiday() {
+        // Trettondedag jul 2026 is a Tuesday, making the Monday a day before a holiday.
+        let monday = NaiveDate::from_ymd(2026, 1, 5);
+        assert!(is_klamdag_recommended(monday));
+        assert!(!is_bridge_day(&monday));
+
+        // An ordinary week has none.
+        assert!(!dates_in(normal_week_2020()).any(is_klamdag_recommended));
+    }
+
+    #[test]
+    fn test_consecutive_holiday_groups_christmas_2020() {
+        let d = |m, d| NaiveDate::from_ymd(2020, m, d);
+        let groups = consecutive_holiday_groups_in_range(d(12, 19)..d(12, 31));
+
+        // Sunday the 20th, then Julafton through Sunday the 27th.
+        assert_eq!(
+            vec![
+                vec![d(12, 20)],
+                vec![d(12, 24), d(12, 25), d(12, 26), d(12, 27)],
+            ],
+            groups
+        );
+    }
+
+    #[test]
+    fn test_consecutive_holiday_groups_easter_2020() {
+        let d = |m, d| NaiveDate::from_ymd(2020, m, d);
+
+        // The Saturday breaks Långfredagen off from the rest.
+        assert_eq!(
+            vec![vec![d(4, 10)], vec![d(4, 12), d(4, 13)]],
+            consecutive_holiday_groups_in_range(d(4, 6)..d(4, 18))
+        );
+    }
+
+    #[test]
+    fn test_statutory_holiday_days_in_employment_year() {
+        // Nyårsdagen, Trettondedag jul, Långfredagen, Annandag påsk, Första maj,
+        // Kristi himmelsfärdsdag and Juldagen.
+        assert_eq!(
+            7,
+            statutory_holiday_days_in_employment_year(NaiveDate::from_ymd(2020, 1, 1))
+        );
+
+        // Juldagen 2020, then Nyårsdagen, Trettondedag jul, Långfredagen,
+        // Annandag påsk and Kristi himmelsfärdsdag 2021.
+        assert_eq!(
+            6,
+            statutory_holiday_days_in_employment_year(NaiveDate::from_ymd(2020, 7, 1))
+        );
+    }
+
+    #[test]
+    fn test_vacation_calendar_2024() {
+        let calendar = VacationCalendar::for_year(2024);
+
+        assert_eq!(2024, calendar.year);
+        assert_eq!(16, calendar.holidays.len());
+        assert!(calendar.holidays.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert_eq!(
+            calendar.working_days,
+            working_days_between(
+                NaiveDate::from_ymd(2024, 1, 1),
+                NaiveDate::from_ymd(2025, 1, 1)
+            )
+        );
+
+        assert!(calendar
+            .bridge_days
+            .contains(&NaiveDate::from_ymd(2024, 5, 10)));
+
+        // Skärtorsdagen through Annandag påsk.
+        assert!(calendar
+            .long_weekends
+            .contains(&(NaiveDate::from_ymd(2024, 3, 28)..NaiveDate::from_ymd(2024, 4, 2))));
+        // The last one is cut off at the end of the year.
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2025, 1, 1)),
+            calendar.long_weekends.last().map(|r| r.end)
+        );
+    }
+
+    #[test]
+    fn test_best_leave_weeks_2024() {
+        let calendar = VacationCalendar::for_year(2024);
+        let best = calendar.best_leave_weeks(5);
+
+        assert_eq!(5, best.len());
+
+        // Christmas week, only the 27th is a working day.
+        assert_eq!(52, best[0].0.week());
+        assert_eq!((6, 1), (best[0].1, best[0].2));
+
+        // Easter week, Skärtorsdagen through Sunday is off.
+        assert!(best
+            .iter()
+            .any(|(week, off, needed)| { week.week() == 13 && *off == 4 && *needed == 3 }));
+
+        assert!(best.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert_eq!(52, calendar.best_leave_weeks(100).len());
+    }
+
+    #[test]
+    fn test_easter_cluster_2020() {
+        // Skärtorsdagen through Annandag påsk.
+        let cluster = NaiveDate::from_ymd(2020, 4, 9)..NaiveDate::from_ymd(2020, 4, 14);
+
+        let easter = dates_in(NaiveDate::from_ymd(2020, 4, 10)..NaiveDate::from_ymd(2020, 4, 14))
+            .collect::<Vec<_>>();
+        for a in &easter {
+            assert_eq!(cluster, holiday_cluster_for(*a));
+            for b in &easter {
+                assert!(are_same_holiday_cluster(*a, *b), "{} {}", a, b);
+            }
+        }
+
+        // Across the working days before the next weekend.
+        assert!(!are_same_holiday_cluster(
+            NaiveDate::from_ymd(2020, 4, 13),
+            NaiveDate::from_ymd(2020, 4, 18)
+        ));
+    }
+
+    #[test]
+    fn test_cluster_for_working_day() {
+        let monday = NaiveDate::from_ymd(2020, 9, 14);
+        assert_eq!(monday..monday, holiday_cluster_for(monday));
+        assert!(!are_same_holiday_cluster(monday, monday));
+
+        // An ordinary weekend.
+        let saturday = NaiveDate::from_ymd(2020, 9, 19);
+        assert_eq!(
+            saturday..NaiveDate::from_ymd(2020, 9, 21),
+            holiday_cluster_for(saturday.succ())
+        );
+    }
+}
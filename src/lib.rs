@@ -1,60 +1,337 @@
+// `chrono::Date` is deprecated upstream but is still part of this crate's public API.
+#![allow(deprecated)]
+
+pub mod dates;
+pub mod locale;
+
+mod calendar;
+mod color;
 mod day_kind;
+mod easter;
+mod eve;
+mod floating;
+mod format;
+mod hours;
+mod kind_set;
+mod leave;
+mod policy;
+mod rules;
+mod schedule;
+mod season;
+mod support;
+#[cfg(feature = "time-compat")]
+mod time_compat;
+mod util;
+mod week;
+mod working_days;
 
-use chrono::{Date, Datelike, Duration, TimeZone, Weekday};
+use chrono::{Date, DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
 use chrono_tz::{Europe::Stockholm, Tz};
-use std::{fmt, iter};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt, iter,
+    ops::{self, Range},
+};
+
+pub use calendar::{
+    BuilderError, BusinessCalendar, CalendarConfig, CalendarYear, DayEntry, HolidayCalendarBuilder,
+    ObservedPolicy,
+};
+pub use color::day_kind_color;
+#[cfg(feature = "css-colors")]
+pub use color::day_kind_hex_color;
+pub use day_kind::{
+    classify, classify_datetime, classify_days, day_kind, day_kind_with, day_kind_with_reason,
+    days_in_range_by_kind, max_kind_in_range, slice_on_day_kind, slice_on_day_kind_dates,
+    slice_on_day_kind_with, ClassifiedDay, DayKind, DayKindDateSlice, DayKindInstant, DayKindSlice,
+    DayKindSliceIter, DayKindSliceStream, HasDayKind, MultipleKindsError, ParseDayKindError,
+    PeekableDayKindSliceIter, SliceError,
+};
+pub use easter::{easter_day_for_year, easter_monday, easter_sunday, good_friday, maundy_thursday};
+pub use eve::{
+    half_day_eves_in_year, holiday_eve_chain, holiday_eve_for, holiday_for_eve, is_half_day_eve,
+    Eve,
+};
+pub use floating::{all_saints_day, midsummer_day, midsummer_eve};
+pub use format::{
+    format_day_kind_report, format_duration_as_hours_minutes, format_duration_decimal_hours,
+    format_duration_hm_signed, format_holiday_date, parse_holiday_date, parse_hours_minutes,
+    ParseDurationError, ParseHolidayError,
+};
+pub use hours::{compute_working_hours_report, WorkingHoursReport};
+pub use kind_set::{day_kinds, DayKindSet};
+pub use leave::{
+    are_same_holiday_cluster, consecutive_holiday_groups_in_range, count_vacation_days_needed,
+    holiday_cluster_for, holiday_density, is_bridge_day, is_klamdag_recommended,
+    non_working_density, statutory_holiday_days_in_employment_year, vacation_efficiency,
+    VacationCalendar,
+};
+pub use policy::{DayKindPolicy, Reason};
+pub use rules::RuleSet;
+pub use schedule::{
+    calendar_working_minutes_between, overtime_threshold_reached, working_hours_available,
+    working_hours_in_range, working_minutes_between, working_seconds_between, Schedule,
+};
+pub use season::{seasons_in_year, HolidaySeason};
+pub use support::{
+    is_supported_year, try_day_kind, try_next_upcoming_holiday, UnsupportedYear, SUPPORTED_YEARS,
+};
+#[cfg(feature = "time-compat")]
+pub use time_compat::{next_holiday_time, time_date_to_day_kind};
+pub use util::{
+    all_sundays_in_month, all_sundays_in_year, calendar_days_in_range, days_between,
+    iso_week_for_date, nth_weekday_of_month, overlapping_range_duration, ranges_overlap,
+};
+pub use week::{day_kind_schedule_for_week, holiday_schedule_for_week};
+pub use working_days::{
+    first_working_day_of_year, is_working_day, last_working_day_of_month, last_working_day_of_year,
+    nth_working_day_of_month, quarter_working_days, working_day_from_index,
+    working_day_index_in_year, working_days_between, working_days_in_month,
+    working_days_in_quarter, working_days_in_year,
+};
 
-pub use day_kind::{day_kind, slice_on_day_kind, DayKind, DayKindSlice};
+/// The timezone of every date and time in this crate.
+/// ```
+/// use chrono::TimeZone;
+/// use holidays_se::{day_kind, DayKind, SWEDISH_TZ};
+///
+/// let midsummer = SWEDISH_TZ.ymd(2020, 6, 20);
+/// assert_eq!(DayKind::Holiday, day_kind(&midsummer));
+/// ```
+pub const SWEDISH_TZ: Tz = Stockholm;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Same as `SWEDISH_TZ`.
+/// ```
+/// use chrono::TimeZone;
+/// use holidays_se::swedish_timezone;
+///
+/// let start = swedish_timezone().ymd(2020, 9, 17).and_hms(8, 0, 0);
+/// assert_eq!("2020-09-17 08:00:00 CEST", start.to_string());
+/// ```
+pub fn swedish_timezone() -> Tz {
+    SWEDISH_TZ
+}
+
+/// Returns the current time in Sweden.
+/// ```
+/// use holidays_se::{now_in_sweden, SWEDISH_TZ};
+///
+/// assert_eq!(SWEDISH_TZ, now_in_sweden().timezone());
+/// ```
+pub fn now_in_sweden() -> DateTime<Tz> {
+    Utc::now().with_timezone(&SWEDISH_TZ)
+}
+
+/// The Swedish holidays, "röda dagar", in the order they occur during a year.
+///
+/// Julafton, Midsommarafton and Nyårsafton aren't public holidays by law, but are days off
+/// de facto and treated as holidays throughout this crate.
+///
+/// Use `holiday_at` to find the holiday of a date, and `in_year` for the date of a holiday.
+/// Which of them are in effect depends on the year, see `RuleSet`.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{holiday_at, Holiday};
+///
+/// match holiday_at(NaiveDate::from_ymd(2024, 3, 29)) {
+///     Some(Holiday::Langfredagen) => {}
+///     other => panic!("Expected Långfredagen, got {:?}", other),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Holiday {
+    /// New Year's Day, the 1st of January.
     Nyarsdagen,
+    /// Epiphany, the 6th of January.
     TrettondedagJul,
+    /// Good Friday, the Friday before Påskdagen.
     Langfredagen,
+    /// Easter Sunday.
     Paskdagen,
+    /// Easter Monday, the day after Påskdagen.
     AnnandagPask,
+    /// May Day, the 1st of May.
     ForstaMaj,
+    /// Ascension Day, the 6th Thursday after Påskdagen.
     KristiHimmelfardsdag,
+    /// Whit Sunday, the 7th Sunday after Påskdagen.
     Pingstdagen,
+    /// Whit Monday, the day after Pingstdagen. A holiday until 2004.
+    AnnandagPingst,
+    /// The National Day of Sweden, the 6th of June. A holiday since 2005.
     Nationaldagen,
+    /// Midsummer Eve, the Friday between the 19th and 25th of June.
     Midsommarafton,
+    /// Midsummer Day, the Saturday between the 20th and 26th of June.
     Midsommardagen,
+    /// All Saints' Day, the Saturday between the 31st of October and 6th of November.
     AllaHelgonsDag,
+    /// Christmas Eve, the 24th of December.
     Julafton,
+    /// Christmas Day, the 25th of December.
     Juldagen,
+    /// Boxing Day, the 26th of December.
     AnnandagJul,
+    /// New Year's Eve, the 31st of December.
     Nyarsafton,
 }
 
 impl Holiday {
-    pub fn in_year(self, year: i32) -> Date<Tz> {
+    /// Every holiday, in the same order as `holidays()`.
+    /// ```
+    /// use holidays_se::Holiday;
+    ///
+    /// let names = Holiday::ALL.iter().map(|h| h.english_name());
+    /// assert_eq!(Some("New Year's Day"), names.clone().next());
+    /// assert_eq!(17, names.count());
+    /// ```
+    pub const ALL: [Holiday; 17] = [
+        Self::Nyarsdagen,
+        Self::TrettondedagJul,
+        Self::Langfredagen,
+        Self::Paskdagen,
+        Self::AnnandagPask,
+        Self::ForstaMaj,
+        Self::KristiHimmelfardsdag,
+        Self::Pingstdagen,
+        Self::AnnandagPingst,
+        Self::Nationaldagen,
+        Self::Midsommarafton,
+        Self::Midsommardagen,
+        Self::AllaHelgonsDag,
+        Self::Julafton,
+        Self::Juldagen,
+        Self::AnnandagJul,
+        Self::Nyarsafton,
+    ];
+
+    /// Returns the date of the holiday in year.
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use holidays_se::Holiday;
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::from_ymd(2027, 5, 6),
+    ///     Holiday::KristiHimmelfardsdag.date_in(2027)
+    /// );
+    /// ```
+    pub fn date_in(self, year: i32) -> NaiveDate {
         match self {
-            Self::Nyarsdagen => Stockholm.ymd(year, 1, 1),
-            Self::TrettondedagJul => Stockholm.ymd(year, 1, 6),
-            Self::ForstaMaj => Stockholm.ymd(year, 5, 1),
-            Self::Nationaldagen => Stockholm.ymd(year, 6, 6),
-            Self::Julafton => Stockholm.ymd(year, 12, 24),
-            Self::Juldagen => Stockholm.ymd(year, 12, 25),
-            Self::AnnandagJul => Stockholm.ymd(year, 12, 26),
-            Self::Nyarsafton => Stockholm.ymd(year, 12, 31),
+            Self::Nyarsdagen => dates::new_years_day(year),
+            Self::TrettondedagJul => dates::epiphany(year),
+            Self::ForstaMaj => dates::labor_day(year),
+            Self::Nationaldagen => dates::national_day(year),
+            Self::Julafton => dates::christmas_eve(year),
+            Self::Juldagen => dates::christmas_day(year),
+            Self::AnnandagJul => dates::boxing_day(year),
+            Self::Nyarsafton => dates::new_years_eve(year),
 
             // Weekday related stuff.
-            Self::Midsommarafton => closest_next(Stockholm.ymd(year, 6, 19), Weekday::Fri),
-            Self::Midsommardagen => closest_next(Stockholm.ymd(year, 6, 20), Weekday::Sat),
-
-            // Saturday between 31 oct - 6 nov.
-            Self::AllaHelgonsDag => closest_next(Stockholm.ymd(year, 10, 31), Weekday::Sat),
+            Self::Midsommarafton => midsummer_eve(year),
+            Self::Midsommardagen => midsummer_day(year),
+            Self::AllaHelgonsDag => all_saints_day(year),
 
             // Easter related stuff.....
-            Self::Langfredagen => easter_day_for_year(year) - Duration::days(2),
-            Self::Paskdagen => easter_day_for_year(year),
-            Self::AnnandagPask => easter_day_for_year(year) + Duration::days(1),
+            Self::Langfredagen => good_friday(year),
+            Self::Paskdagen => easter_sunday(year),
+            Self::AnnandagPask => easter_monday(year),
             Self::KristiHimmelfardsdag => {
-                easter_day_for_year(year) + Duration::weeks(5) + Duration::days(4)
+                easter_sunday(year) + Duration::weeks(5) + Duration::days(4)
             }
-            Self::Pingstdagen => easter_day_for_year(year) + Duration::weeks(7),
+            Self::Pingstdagen => easter_sunday(year) + Duration::weeks(7),
+            Self::AnnandagPingst => easter_sunday(year) + Duration::weeks(7) + Duration::days(1),
+        }
+    }
+
+    /// Returns the holiday falling on date, the reverse of `date_in`. Same as `holiday_at`.
+    ///
+    /// Unlike `day_kind`, which says `DayKind::Holiday` for every Sunday, this is only
+    /// `Some` for the named holidays.
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use holidays_se::Holiday;
+    ///
+    /// assert_eq!(
+    ///     Some(Holiday::Midsommarafton),
+    ///     Holiday::from_date(NaiveDate::from_ymd(2024, 6, 21))
+    /// );
+    /// assert_eq!(None, Holiday::from_date(NaiveDate::from_ymd(2024, 6, 23)));
+    /// ```
+    pub fn from_date(date: NaiveDate) -> Option<Self> {
+        holiday_at(date)
+    }
+
+    /// Returns the named eve of the holiday, like Påskafton for Påskdagen.
+    /// `None` for holidays without one, like Kristi himmelsfärdsdag.
+    pub fn eve(self) -> Option<Eve> {
+        Eve::ALL.iter().copied().find(|eve| eve.holiday() == self)
+    }
+
+    /// Returns true if the holiday is in effect in year, see `RuleSet`.
+    pub fn is_holiday_in(self, year: i32) -> bool {
+        RuleSet::for_year(year).contains(self)
+    }
+
+    /// Same as `date_in` but as a Stockholm date.
+    pub fn in_year(self, year: i32) -> Date<Tz> {
+        Stockholm.from_local_date(&self.date_in(year)).unwrap()
+    }
+
+    /// The English name of the holiday. `Display` writes the Swedish one.
+    pub fn english_name(self) -> &'static str {
+        match self {
+            Self::Nyarsdagen => "New Year's Day",
+            Self::TrettondedagJul => "Epiphany",
+            Self::Langfredagen => "Good Friday",
+            Self::Paskdagen => "Easter Sunday",
+            Self::AnnandagPask => "Easter Monday",
+            Self::ForstaMaj => "May Day",
+            Self::KristiHimmelfardsdag => "Ascension Day",
+            Self::Pingstdagen => "Whit Sunday",
+            Self::AnnandagPingst => "Whit Monday",
+            Self::Nationaldagen => "National Day",
+            Self::Midsommarafton => "Midsummer Eve",
+            Self::Midsommardagen => "Midsummer Day",
+            Self::AllaHelgonsDag => "All Saints' Day",
+            Self::Julafton => "Christmas Eve",
+            Self::Juldagen => "Christmas Day",
+            Self::AnnandagJul => "Boxing Day",
+            Self::Nyarsafton => "New Year's Eve",
         }
     }
+
+    /// Returns true for the public holidays by law, the "röda dagar".
+    /// The opposite of `is_de_facto`.
+    pub fn is_red_day(self) -> bool {
+        !self.is_de_facto()
+    }
+
+    /// Returns true for Julafton, Midsommarafton and Nyårsafton. These aren't public holidays
+    /// by law, but are days off in practice.
+    pub fn is_de_facto(self) -> bool {
+        matches!(
+            self,
+            Self::Julafton | Self::Midsommarafton | Self::Nyarsafton
+        )
+    }
+
+    /// Returns true if the date of the holiday varies between years, either following Easter
+    /// or falling on a given weekday. False for the holidays on a fixed day of the year.
+    pub fn is_movable(self) -> bool {
+        matches!(
+            self,
+            Self::Langfredagen
+                | Self::Paskdagen
+                | Self::AnnandagPask
+                | Self::KristiHimmelfardsdag
+                | Self::Pingstdagen
+                | Self::AnnandagPingst
+                | Self::Midsommarafton
+                | Self::Midsommardagen
+                | Self::AllaHelgonsDag
+        )
+    }
 }
 
 impl fmt::Display for Holiday {
@@ -67,8 +344,9 @@ impl fmt::Display for Holiday {
             Paskdagen => f.write_str("Påskdagen"),
             AnnandagPask => f.write_str("Annandag påsk"),
             ForstaMaj => f.write_str("Första maj"),
-            KristiHimmelfardsdag => f.write_str(""),
+            KristiHimmelfardsdag => f.write_str("Kristi himmelsfärdsdag"),
             Pingstdagen => f.write_str("Pingstdagen"),
+            AnnandagPingst => f.write_str("Annandag pingst"),
             Nationaldagen => f.write_str("Nationaldagen"),
             Midsommarafton => f.write_str("Midsommarafton"),
             Midsommardagen => f.write_str("Midsommardagen"),
@@ -81,46 +359,444 @@ impl fmt::Display for Holiday {
     }
 }
 
-/// Magic function. No Idea what it does. Just know that i works.
-#[allow(clippy::many_single_char_names)]
-pub fn easter_day_for_year(year: i32) -> Date<Tz> {
-    let a = year % 19;
-    let b = year / 100;
-    let c = (b - (b / 4) - ((8 * b + 13) / 25) + (19 * a) + 15) % 30;
-    let d = c - (c / 28) * (1 - (c / 28) * (29 / (c + 1)) * ((21 - a) / 11));
-    let e = d - ((year + (year / 4) + d + 2 - b + (b / 4)) % 7);
-    let month = 3 + ((e + 40) / 44);
-    let day = e + 28 - (31 * (month / 4));
-
-    Stockholm.ymd(year, month as u32, day as u32)
-}
-
-pub fn next_upcoming_holiday<D>(date: &D) -> (Holiday, Date<Tz>)
+/// Returns the first holiday on or after date, as `(date, holiday)`.
+///
+/// date itself is returned when it is a holiday. There is always one within the same year,
+/// since Nyårsafton is the last day of every year.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{next_upcoming_holiday, Holiday};
+///
+/// assert_eq!(
+///     (NaiveDate::from_ymd(2020, 6, 6), Holiday::Nationaldagen),
+///     next_upcoming_holiday(&NaiveDate::from_ymd(2020, 6, 5))
+/// );
+/// ```
+pub fn next_upcoming_holiday<D>(date: &D) -> (NaiveDate, Holiday)
 where
     D: Datelike,
 {
     let day_ordinal = date.ordinal();
     holidays_in_year(date.year())
-        .find(|(_, d)| day_ordinal <= d.ordinal())
+        .find(|(d, _)| day_ordinal <= d.ordinal())
         // We're only considering Dates, not time here. So it's impossible to express a day
         // later than 31st december - and since 31st dec is New years eve, it is always last.
         // This expect is here to catch any faulty reasoning.
         .expect("Next upcoming holiday was somehow not found. This is unexpected!")
 }
 
+/// Returns the last holiday on or before date, as `(date, holiday)`.
+/// The backwards counterpart of `next_upcoming_holiday`.
+///
+/// date itself is returned when it is a holiday. There is always one within the same year,
+/// since Nyårsdagen is the first day of every year.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{previous_holiday, Holiday};
+///
+/// assert_eq!(
+///     (NaiveDate::from_ymd(2020, 6, 6), Holiday::Nationaldagen),
+///     previous_holiday(&NaiveDate::from_ymd(2020, 6, 18))
+/// );
+/// ```
+pub fn previous_holiday<D>(date: &D) -> (NaiveDate, Holiday)
+where
+    D: Datelike,
+{
+    let day_ordinal = date.ordinal();
+    holidays_in_year(date.year())
+        .filter(|(d, _)| d.ordinal() <= day_ordinal)
+        .last()
+        // Nyårsdagen is the 1st of January, so there's always one.
+        .expect("Previous holiday was somehow not found. This is unexpected!")
+}
+
+/// Returns true if date is `DayKind::Holiday`, this includes every Sunday.
+pub fn is_holiday(date: NaiveDate) -> bool {
+    day_kind(&date) == DayKind::Holiday
+}
+
+/// Returns true only if date is a named holiday, including the de facto holidays,
+/// see `is_red_day`.
+///
+/// Unlike `is_holiday`, ordinary Sundays are not public holidays.
+/// A Sunday that is also a named holiday, like Påskdagen, is.
+pub fn is_public_holiday(date: NaiveDate) -> bool {
+    holiday_at(date).is_some()
+}
+
+/// Returns true if date is a public holiday by law, see `Holiday::is_red_day`.
+///
+/// Stricter than `is_public_holiday`, Julafton, Midsommarafton and Nyårsafton are not red days.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{is_holiday, is_public_holiday, is_red_day};
+///
+/// let christmas_eve = NaiveDate::from_ymd(2024, 12, 24);
+/// assert!(is_holiday(christmas_eve) && is_public_holiday(christmas_eve));
+/// assert!(!is_red_day(christmas_eve));
+///
+/// // An ordinary Sunday.
+/// let sunday = NaiveDate::from_ymd(2024, 12, 8);
+/// assert!(is_holiday(sunday) && !is_red_day(sunday));
+/// ```
+pub fn is_red_day(date: NaiveDate) -> bool {
+    holiday_at(date).is_some_and(Holiday::is_red_day)
+}
+
+/// Returns true if date is a Sunday, named holiday or not.
+pub fn is_sunday(date: NaiveDate) -> bool {
+    date.weekday() == Weekday::Sun
+}
+
+/// Returns the number of `DayKind::Holiday` days in the month, Sundays included.
+///
+/// Panics if month is not within 1-12.
+pub fn holiday_count_in_month(year: i32, month: u32) -> u32 {
+    dates_in(month_range(year, month))
+        .filter(|d| is_holiday(*d))
+        .count() as u32
+}
+
+/// Returns the number of named holidays in the month, see `is_public_holiday`.
+///
+/// Panics if month is not within 1-12.
+pub fn pub_holiday_count_in_month(year: i32, month: u32) -> u32 {
+    dates_in(month_range(year, month))
+        .filter(|d| is_public_holiday(*d))
+        .count() as u32
+}
+
+/// Returns the named holiday on date, if any.
+/// Ordinary Sundays and days before holidays are not named holidays.
+pub fn holiday_at(date: NaiveDate) -> Option<Holiday> {
+    with_sorted_holidays(date.year(), |holidays| {
+        holidays
+            .binary_search_by_key(&date, |(d, _)| *d)
+            .ok()
+            .map(|i| holidays[i].1)
+    })
+}
+
+/// Returns the next n holidays, in chronological order.
+/// from itself is included if it is a holiday.
+pub fn next_n_holidays(from: NaiveDate, n: usize) -> Vec<(NaiveDate, Holiday)> {
+    upcoming_holidays(from).take(n).collect()
+}
+
+/// Returns the holidays within range, in chronological order.
+///
+/// Holidays coinciding on the same date, like Kristi himmelsfärdsdag and Första maj
+/// in 2008, are both included.
+pub fn holidays_between(range: Range<NaiveDate>) -> Vec<(NaiveDate, Holiday)> {
+    upcoming_holidays(range.start)
+        .take_while(|(d, _)| *d < range.end)
+        .collect()
+}
+
+/// Returns the number of holidays within range, without collecting them.
+/// Same as the length of `holidays_between`.
+pub fn count_holidays_between(range: Range<NaiveDate>) -> usize {
+    upcoming_holidays(range.start)
+        .take_while(|(d, _)| *d < range.end)
+        .count()
+}
+
+/// Returns the nth holiday after date, counting from 1. date itself is never included.
+/// `None` if n is 0.
+///
+/// Holidays coinciding on the same date are counted separately, like in `upcoming_holidays`.
+/// Whole years are skipped by their number of holidays, without computing their dates.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{nth_holiday_after, Holiday};
+///
+/// assert_eq!(
+///     Some((NaiveDate::from_ymd(2024, 12, 26), Holiday::AnnandagJul)),
+///     nth_holiday_after(NaiveDate::from_ymd(2024, 12, 24), 2)
+/// );
+/// ```
+pub fn nth_holiday_after(date: NaiveDate, n: usize) -> Option<(NaiveDate, Holiday)> {
+    let index = n.checked_sub(1)?;
+    let from = date.succ();
+
+    let left_this_year = with_sorted_holidays(from.year(), |holidays| {
+        holidays.iter().filter(|(d, _)| *d >= from).count()
+    });
+    if index < left_this_year {
+        return upcoming_holidays(from).nth(index);
+    }
+
+    let mut index = index - left_this_year;
+    let mut year = from.year() + 1;
+    loop {
+        let per_year = RuleSet::for_year(year).holidays().count();
+        if index < per_year {
+            return Some(with_sorted_holidays(year, |holidays| holidays[index]));
+        }
+        index -= per_year;
+        year += 1;
+    }
+}
+
+/// Returns the n most recent holidays, on or before from, in reverse chronological order.
+/// from itself is included if it is a holiday.
+pub fn previous_n_holidays(from: NaiveDate, n: usize) -> Vec<(NaiveDate, Holiday)> {
+    swedish_holidays_until(from).take(n).collect()
+}
+
+/// Returns the signed number of days to the closest named holiday on or before date,
+/// and on or after date. Both are `Some(0)` if date is a holiday.
+///
+/// The first is `None` only if there is no earlier holiday within chrono's range of dates.
+pub fn holiday_proximity(date: NaiveDate) -> (Option<i64>, Option<i64>) {
+    let previous = swedish_holidays_until(date)
+        .next()
+        .map(|(d, _)| days_between(date, d));
+    let next = upcoming_holidays(date)
+        .next()
+        .map(|(d, _)| days_between(date, d));
+
+    (previous, next)
+}
+
+/// Returns the date of the holiday named name in year.
+/// Both Swedish and English names are accepted, in any case, e.g. "påskdagen" or "Easter Sunday".
+/// `None` if there is no such holiday in year.
+pub fn public_holiday_by_name(name: &str, year: i32) -> Option<NaiveDate> {
+    let name = name.trim().to_lowercase();
+    holidays()
+        .find(|h| h.to_string().to_lowercase() == name || h.english_name().to_lowercase() == name)
+        .filter(|h| h.is_holiday_in(year))
+        .map(|h| public_holiday_by_holiday(h, year))
+}
+
+/// Returns the date of holiday in year, even if it isn't in effect that year.
+pub fn public_holiday_by_holiday(holiday: Holiday, year: i32) -> NaiveDate {
+    holiday.date_in(year)
+}
+
+/// Returns the closest named holiday to date, and the signed number of days to it.
+/// The distance is 0 if date is a holiday, and negative if the holiday has passed.
+/// When two holidays are equally close, the upcoming one is returned.
+pub fn nearest_holiday(date: NaiveDate) -> (NaiveDate, Holiday, i64) {
+    let (next, next_holiday) = upcoming_holidays(date)
+        .next()
+        .expect("There is always an upcoming holiday");
+    let next_distance = days_between(date, next);
+
+    match swedish_holidays_until(date).next() {
+        Some((previous, previous_holiday)) if days_between(previous, date) < next_distance => {
+            (previous, previous_holiday, days_between(date, previous))
+        }
+        _ => (next, next_holiday, next_distance),
+    }
+}
+
+/// Returns every occurrence of holiday within year_range that falls on weekday.
+/// Years the holiday isn't in effect are skipped.
+pub fn holidays_on_weekday(
+    holiday: Holiday,
+    weekday: Weekday,
+    year_range: Range<i32>,
+) -> Vec<(NaiveDate, Holiday)> {
+    year_range
+        .filter(|year| holiday.is_holiday_in(*year))
+        .map(|year| holiday.date_in(year))
+        .filter(|date| date.weekday() == weekday)
+        .map(|date| (date, holiday))
+        .collect()
+}
+
+/// Annotates each date with its `DayKind` and named holiday, if any. The order is kept.
+///
+/// The dates are visited in sorted order, looking up the holidays of each year only once.
+pub fn annotate_dates<T>(dates: &[(NaiveDate, T)]) -> Vec<(NaiveDate, T, DayKind, Option<Holiday>)>
+where
+    T: Clone,
+{
+    let mut order = (0..dates.len()).collect::<Vec<_>>();
+    order.sort_by_key(|i| dates[*i].0);
+
+    let policy = DayKindPolicy::swedish();
+    let mut annotated = vec![None; dates.len()];
+    let mut year = None;
+    let mut holidays = Vec::new();
+    for i in order {
+        let (date, t) = &dates[i];
+        if year != Some(date.year()) {
+            year = Some(date.year());
+            holidays = holidays_in_year(date.year()).collect();
+        }
+
+        // Only the day after New Year's Eve is in another year.
+        let named_holiday = |d: NaiveDate| {
+            if d.year() != date.year() {
+                return holiday_at(d);
+            }
+            holidays
+                .binary_search_by_key(&d, |(date, _)| *date)
+                .ok()
+                .map(|i| holidays[i].1)
+        };
+        let (kind, _) = policy.classify_by(*date, named_holiday);
+        annotated[i] = Some((*date, t.clone(), kind, named_holiday(*date)));
+    }
+
+    annotated.into_iter().flatten().collect()
+}
+
+/// Partitions dates by their `DayKind`, keeping their order within each kind.
+/// Every kind `day_kind` returns has an entry, empty if no date is of that kind.
+pub fn group_dates_by_kind<T>(
+    dates: impl IntoIterator<Item = (NaiveDate, T)>,
+) -> HashMap<DayKind, Vec<(NaiveDate, T)>> {
+    let mut groups = HashMap::with_capacity(3);
+    for kind in [
+        DayKind::Weekday,
+        DayKind::DayBeforeHoliday,
+        DayKind::Holiday,
+    ]
+    .iter()
+    {
+        groups.insert(*kind, Vec::new());
+    }
+
+    for (date, t) in dates {
+        groups.entry(day_kind(&date)).or_default().push((date, t));
+    }
+
+    groups
+}
+
+/// Lazily iterates all holidays on or after from, in chronological order.
+///
+/// The iterator crosses year boundaries on its own and never ends, so limit it with
+/// e.g. `take` or `take_while`.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{upcoming_holidays, Holiday};
+///
+/// let mut holidays = upcoming_holidays(NaiveDate::from_ymd(2020, 12, 26));
+/// assert_eq!(
+///     Some((NaiveDate::from_ymd(2020, 12, 26), Holiday::AnnandagJul)),
+///     holidays.next()
+/// );
+/// assert_eq!(
+///     Some((NaiveDate::from_ymd(2020, 12, 31), Holiday::Nyarsafton)),
+///     holidays.next()
+/// );
+/// assert_eq!(
+///     Some((NaiveDate::from_ymd(2021, 1, 1), Holiday::Nyarsdagen)),
+///     holidays.next()
+/// );
+/// ```
+pub fn upcoming_holidays(from: NaiveDate) -> impl Iterator<Item = (NaiveDate, Holiday)> {
+    (from.year()..)
+        .flat_map(|year| with_sorted_holidays(year, <[_]>::to_vec))
+        .skip_while(move |(d, _)| *d < from)
+}
+
+/// Lazily iterates all holidays on or before from, in reverse chronological order.
+/// Stops at the first year chrono can't represent.
+pub(crate) fn swedish_holidays_until(
+    from: NaiveDate,
+) -> impl Iterator<Item = (NaiveDate, Holiday)> {
+    iter::successors(Some(from.year()), |year| year.checked_sub(1))
+        .take_while(|year| NaiveDate::from_ymd_opt(*year, 1, 1).is_some())
+        .flat_map(|year| {
+            let mut holidays = with_sorted_holidays(year, <[_]>::to_vec);
+            holidays.reverse();
+            holidays
+        })
+        .skip_while(move |(d, _)| *d > from)
+}
+
+/// The holidays of a year, ordered by date.
+type HolidayTable = Vec<(NaiveDate, Holiday)>;
+
+thread_local! {
+    static HOLIDAY_CACHE: RefCell<Option<(i32, HolidayTable)>> = const { RefCell::new(None) };
+}
+
+/// Calls f with the holidays of year, ordered by date.
+/// The holidays of the most recently used year are cached per thread.
+pub(crate) fn with_sorted_holidays<F, R>(year: i32, f: F) -> R
+where
+    F: FnOnce(&[(NaiveDate, Holiday)]) -> R,
+{
+    HOLIDAY_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        match &*cache {
+            Some((cached_year, _)) if *cached_year == year => {}
+            _ => {
+                let mut holidays = RuleSet::for_year(year)
+                    .holidays()
+                    .map(|h| (h.date_in(year), h))
+                    .collect::<Vec<_>>();
+                holidays.sort_by_key(|(d, _)| *d);
+                *cache = Some((year, holidays));
+            }
+        }
+
+        let (_, holidays) = cache.as_ref().expect("Holiday cache was just populated");
+        f(holidays)
+    })
+}
+
 #[derive(Clone, Copy)]
 struct Holidays {
     next: Option<Holiday>,
 }
 
-pub fn holidays() -> impl Iterator<Item = Holiday> + Clone + Copy {
+pub fn holidays() -> impl Iterator<Item = Holiday> + Copy {
     Holidays {
         next: Some(Holiday::Nyarsdagen),
     }
 }
 
-pub fn holidays_in_year(year: i32) -> impl Iterator<Item = (Holiday, Date<Tz>)> + Clone {
-    holidays().map(move |h| (h, h.in_year(year)))
+/// Returns every holiday of the year, in chronological order.
+pub fn holidays_in_year(year: i32) -> impl Iterator<Item = (NaiveDate, Holiday)> + Clone {
+    with_sorted_holidays(year, <[_]>::to_vec).into_iter()
+}
+
+/// A holiday together with its date, for listing holidays.
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use holidays_se::{holiday_dates_in_year, Holiday};
+///
+/// let christmas = holiday_dates_in_year(2021)
+///     .into_iter()
+///     .find(|h| h.holiday == Holiday::Juldagen)
+///     .unwrap();
+/// assert_eq!(NaiveDate::from_ymd(2021, 12, 25), christmas.date);
+/// assert_eq!(Weekday::Sat, christmas.weekday);
+/// assert!(christmas.falls_on_weekend);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HolidayDate {
+    pub holiday: Holiday,
+    pub date: NaiveDate,
+    pub weekday: Weekday,
+    /// True if date is a Saturday or Sunday, a day off anyway.
+    pub falls_on_weekend: bool,
+}
+
+impl From<(NaiveDate, Holiday)> for HolidayDate {
+    fn from((date, holiday): (NaiveDate, Holiday)) -> Self {
+        let weekday = date.weekday();
+        Self {
+            holiday,
+            date,
+            weekday,
+            falls_on_weekend: weekday == Weekday::Sat || weekday == Weekday::Sun,
+        }
+    }
+}
+
+/// Same as `holidays_in_year`, as `HolidayDate`s.
+pub fn holiday_dates_in_year(year: i32) -> Vec<HolidayDate> {
+    holidays_in_year(year).map(HolidayDate::from).collect()
 }
 
 impl iter::Iterator for Holidays {
@@ -165,10 +841,15 @@ impl iter::Iterator for Holidays {
             }
 
             Some(Pingstdagen) => {
-                self.next = Some(Nationaldagen);
+                self.next = Some(AnnandagPingst);
                 Some(Pingstdagen)
             }
 
+            Some(AnnandagPingst) => {
+                self.next = Some(Nationaldagen);
+                Some(AnnandagPingst)
+            }
+
             Some(Nationaldagen) => {
                 self.next = Some(Midsommarafton);
                 Some(Nationaldagen)
@@ -214,8 +895,31 @@ impl iter::Iterator for Holidays {
     }
 }
 
+/// Returns the dates of the given month.
+/// Panics if month is not within 1-12.
+pub(crate) fn month_range(year: i32, month: u32) -> Range<NaiveDate> {
+    assert!((1..=12).contains(&month), "Invalid month: {}", month);
+
+    let start = NaiveDate::from_ymd(year, month, 1);
+    let end = if month == 12 {
+        NaiveDate::from_ymd(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd(year, month + 1, 1)
+    };
+
+    start..end
+}
+
+/// Iterates every date in the half-open `range`.
+pub(crate) fn dates_in(range: Range<NaiveDate>) -> impl Iterator<Item = NaiveDate> {
+    iter::successors(Some(range.start), |d| Some(d.succ())).take_while(move |d| *d < range.end)
+}
+
 /// Jumps to the closest next coming day of target weekday
-fn closest_next(d: Date<Tz>, target: chrono::Weekday) -> Date<Tz> {
+fn closest_next<D>(d: D, target: chrono::Weekday) -> D
+where
+    D: Datelike + ops::Add<Duration, Output = D>,
+{
     let days_left_in_week = 7 - d.weekday().num_days_from_monday();
     let days_to_jump = (target.num_days_from_monday() + days_left_in_week) % 7;
     d + Duration::days(days_to_jump.into())
@@ -226,6 +930,16 @@ mod tests {
 
     use super::*;
 
+    mod trait_bounds {
+        use crate::{DayKind, DayKindSlice, DayKindSliceIter, Holiday};
+        use static_assertions::assert_impl_all;
+
+        assert_impl_all!(DayKindSliceIter: Send, Sync, Clone);
+        assert_impl_all!(DayKindSlice: Clone, Send, Sync);
+        assert_impl_all!(DayKind: Copy, Send, Sync);
+        assert_impl_all!(Holiday: Copy, Send, Sync);
+    }
+
     #[test]
     fn test_jumping_between_weekdays() {
         assert_eq!(
@@ -259,26 +973,580 @@ mod tests {
         assert_eq!(Stockholm.ymd(2020, 5, 31), easter + Duration::weeks(7));
     }
 
+    #[test]
+    fn test_date_in() {
+        assert_eq!(
+            NaiveDate::from_ymd(2020, 5, 21),
+            Holiday::KristiHimmelfardsdag.date_in(2020)
+        );
+        assert_eq!(
+            NaiveDate::from_ymd(2021, 11, 6),
+            Holiday::AllaHelgonsDag.date_in(2021)
+        );
+    }
+
+    #[test]
+    fn test_holiday_from_date() {
+        for year in 2018..=2030 {
+            for (date, holiday) in holidays_in_year(year) {
+                let found = Holiday::from_date(date).expect("Should be a holiday");
+                // Coinciding holidays share a date, either is fine.
+                assert_eq!(date, found.date_in(year), "{:?}", holiday);
+            }
+        }
+
+        // An ordinary Sunday and a day before a holiday.
+        assert_eq!(None, Holiday::from_date(NaiveDate::from_ymd(2024, 3, 10)));
+        assert_eq!(None, Holiday::from_date(NaiveDate::from_ymd(2024, 12, 23)));
+    }
+
+    #[test]
+    fn test_all_holidays() {
+        assert_eq!(Holiday::ALL.to_vec(), holidays().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_holiday_dates_in_year() {
+        let dates = holiday_dates_in_year(2021);
+        assert_eq!(16, dates.len());
+
+        // Första maj, Nationaldagen, Juldagen and Annandag jul, besides the holidays
+        // always on a weekend.
+        let weekend = dates
+            .iter()
+            .filter(|h| h.falls_on_weekend)
+            .map(|h| h.holiday)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Holiday::Paskdagen,
+                Holiday::ForstaMaj,
+                Holiday::Pingstdagen,
+                Holiday::Nationaldagen,
+                Holiday::Midsommardagen,
+                Holiday::AllaHelgonsDag,
+                Holiday::Juldagen,
+                Holiday::AnnandagJul,
+            ],
+            weekend
+        );
+    }
+
+    #[test]
+    fn test_holiday_metadata() {
+        let de_facto = holidays().filter(|h| h.is_de_facto()).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Holiday::Midsommarafton,
+                Holiday::Julafton,
+                Holiday::Nyarsafton
+            ],
+            de_facto
+        );
+        assert_eq!(14, holidays().filter(|h| h.is_red_day()).count());
+
+        assert!(Holiday::Pingstdagen.is_movable());
+        assert!(Holiday::AllaHelgonsDag.is_movable());
+        assert!(!Holiday::Nationaldagen.is_movable());
+        assert!(!Holiday::Nyarsafton.is_movable());
+
+        // Fixed holidays fall on the same day of the month every year.
+        for holiday in holidays().filter(|h| !h.is_movable()) {
+            let date = holiday.date_in(2020);
+            for year in 2021..2030 {
+                let other = holiday.date_in(year);
+                assert_eq!((date.month(), date.day()), (other.month(), other.day()));
+            }
+        }
+    }
+
     #[test]
     fn test_next_upcoming_holiday() {
         assert_eq!(
-            (Holiday::Nationaldagen, Stockholm.ymd(2020, 6, 6)),
+            (NaiveDate::from_ymd(2020, 6, 6), Holiday::Nationaldagen),
             super::next_upcoming_holiday(&Stockholm.ymd(2020, 6, 5))
         );
 
         assert_eq!(
-            (Holiday::Langfredagen, Stockholm.ymd(2020, 4, 10)),
+            (NaiveDate::from_ymd(2020, 4, 10), Holiday::Langfredagen),
             super::next_upcoming_holiday(&Stockholm.ymd(2020, 3, 29))
         );
 
         assert_eq!(
-            (Holiday::Nyarsafton, Stockholm.ymd(2020, 12, 31)),
+            (NaiveDate::from_ymd(2020, 12, 31), Holiday::Nyarsafton),
             super::next_upcoming_holiday(&Stockholm.ymd(2020, 12, 31))
         );
 
         assert_eq!(
-            (Holiday::Nyarsdagen, Stockholm.ymd(2020, 1, 1)),
+            (NaiveDate::from_ymd(2020, 1, 1), Holiday::Nyarsdagen),
             super::next_upcoming_holiday(&Stockholm.ymd(2020, 1, 1))
         );
     }
+
+    #[test]
+    fn test_previous_holiday() {
+        assert_eq!(
+            (NaiveDate::from_ymd(2020, 4, 13), Holiday::AnnandagPask),
+            previous_holiday(&NaiveDate::from_ymd(2020, 4, 30))
+        );
+        assert_eq!(
+            (NaiveDate::from_ymd(2020, 5, 1), Holiday::ForstaMaj),
+            previous_holiday(&NaiveDate::from_ymd(2020, 5, 1))
+        );
+        assert_eq!(
+            (NaiveDate::from_ymd(2020, 1, 1), Holiday::Nyarsdagen),
+            previous_holiday(&NaiveDate::from_ymd(2020, 1, 5))
+        );
+        assert_eq!(
+            (NaiveDate::from_ymd(2020, 12, 31), Holiday::Nyarsafton),
+            previous_holiday(&Stockholm.ymd(2020, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_public_holidays_vs_holidays() {
+        // April 2020
+        let sundays = [5, 12, 19, 26];
+        let named = [10, 12, 13]; // Långfredagen, Påskdagen, Annandag påsk
+
+        for day in 1..=30 {
+            let date = NaiveDate::from_ymd(2020, 4, day);
+            assert_eq!(sundays.contains(&day), is_sunday(date), "{}", date);
+            assert_eq!(named.contains(&day), is_public_holiday(date), "{}", date);
+            assert_eq!(named.contains(&day), is_red_day(date), "{}", date);
+            assert_eq!(
+                sundays.contains(&day) || named.contains(&day),
+                is_holiday(date),
+                "{}",
+                date
+            );
+        }
+    }
+
+    #[test]
+    fn test_red_days_vs_de_facto_holidays() {
+        for (date, holiday) in holidays_in_year(2024) {
+            assert!(is_holiday(date) && is_public_holiday(date));
+            assert_eq!(!holiday.is_de_facto(), is_red_day(date), "{}", date);
+        }
+    }
+
+    #[test]
+    fn test_holiday_count_in_month() {
+        // 4 Sundays, one of which is Påskdagen, plus Långfredagen and Annandag påsk.
+        assert_eq!(6, holiday_count_in_month(2020, 4));
+        assert_eq!(3, pub_holiday_count_in_month(2020, 4));
+
+        // September has no named holidays, only Sundays.
+        assert_eq!(4, holiday_count_in_month(2020, 9));
+        assert_eq!(0, pub_holiday_count_in_month(2020, 9));
+
+        // December 2020: 24th, 25th, 26th, 31st and 4 Sundays, one of which is the 27th.
+        assert_eq!(8, holiday_count_in_month(2020, 12));
+        assert_eq!(4, pub_holiday_count_in_month(2020, 12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_holiday_count_in_invalid_month() {
+        holiday_count_in_month(2020, 13);
+    }
+
+    #[test]
+    fn test_holiday_at() {
+        let april_2020 = [
+            (10, Holiday::Langfredagen),
+            (12, Holiday::Paskdagen),
+            (13, Holiday::AnnandagPask),
+        ];
+        for day in 1..=30 {
+            let expected = april_2020.iter().find(|(d, _)| *d == day).map(|(_, h)| *h);
+            assert_eq!(expected, holiday_at(NaiveDate::from_ymd(2020, 4, day)));
+        }
+
+        let december_2025 = [
+            (24, Holiday::Julafton),
+            (25, Holiday::Juldagen),
+            (26, Holiday::AnnandagJul),
+            (31, Holiday::Nyarsafton),
+        ];
+        for day in 1..=31 {
+            let expected = december_2025
+                .iter()
+                .find(|(d, _)| *d == day)
+                .map(|(_, h)| *h);
+            assert_eq!(expected, holiday_at(NaiveDate::from_ymd(2025, 12, day)));
+        }
+    }
+
+    #[test]
+    fn test_next_n_holidays() {
+        // Långfredagen, through the whole Easter weekend.
+        assert_eq!(
+            vec![
+                (NaiveDate::from_ymd(2024, 3, 29), Holiday::Langfredagen),
+                (NaiveDate::from_ymd(2024, 3, 31), Holiday::Paskdagen),
+                (NaiveDate::from_ymd(2024, 4, 1), Holiday::AnnandagPask),
+            ],
+            next_n_holidays(NaiveDate::from_ymd(2024, 3, 29), 3)
+        );
+
+        assert!(next_n_holidays(NaiveDate::from_ymd(2024, 3, 29), 0).is_empty());
+    }
+
+    #[test]
+    fn test_next_n_holidays_into_next_year() {
+        assert_eq!(
+            vec![
+                (NaiveDate::from_ymd(2024, 12, 31), Holiday::Nyarsafton),
+                (NaiveDate::from_ymd(2025, 1, 1), Holiday::Nyarsdagen),
+                (NaiveDate::from_ymd(2025, 1, 6), Holiday::TrettondedagJul),
+            ],
+            next_n_holidays(NaiveDate::from_ymd(2024, 12, 27), 3)
+        );
+    }
+
+    #[test]
+    fn test_upcoming_holidays_across_years() {
+        let from = NaiveDate::from_ymd(2020, 6, 1);
+        let upcoming = upcoming_holidays(from)
+            .take_while(|(d, _)| d.year() < 2031)
+            .collect::<Vec<_>>();
+
+        let expected = (2020..2031)
+            .flat_map(holidays_in_year)
+            .filter(|(d, _)| *d >= from)
+            .collect::<Vec<_>>();
+        assert_eq!(expected, upcoming);
+    }
+
+    #[test]
+    fn test_holidays_between() {
+        let range = NaiveDate::from_ymd(2024, 11, 1)..NaiveDate::from_ymd(2025, 1, 6);
+        assert_eq!(
+            vec![
+                (NaiveDate::from_ymd(2024, 11, 2), Holiday::AllaHelgonsDag),
+                (NaiveDate::from_ymd(2024, 12, 24), Holiday::Julafton),
+                (NaiveDate::from_ymd(2024, 12, 25), Holiday::Juldagen),
+                (NaiveDate::from_ymd(2024, 12, 26), Holiday::AnnandagJul),
+                (NaiveDate::from_ymd(2024, 12, 31), Holiday::Nyarsafton),
+                (NaiveDate::from_ymd(2025, 1, 1), Holiday::Nyarsdagen),
+            ],
+            holidays_between(range.clone())
+        );
+        assert_eq!(6, count_holidays_between(range));
+
+        // Kristi himmelsfärdsdag on Första maj.
+        let may_2008 = month_range(2008, 5);
+        assert_eq!(3, count_holidays_between(may_2008.clone()));
+        assert_eq!(
+            pub_holiday_count_in_month(2008, 5) + 1,
+            count_holidays_between(may_2008) as u32
+        );
+
+        let date = NaiveDate::from_ymd(2024, 12, 24);
+        assert_eq!(0, count_holidays_between(date..date));
+        assert!(holidays_between(date..date.pred()).is_empty());
+    }
+
+    #[test]
+    fn test_nth_holiday_after() {
+        let date = NaiveDate::from_ymd(2024, 12, 25);
+        assert_eq!(None, nth_holiday_after(date, 0));
+        assert_eq!(
+            Some((NaiveDate::from_ymd(2024, 12, 26), Holiday::AnnandagJul)),
+            nth_holiday_after(date, 1)
+        );
+
+        // Across several years, and from a holiday on the 31st of December.
+        for date in &[date, NaiveDate::from_ymd(2024, 12, 31)] {
+            let expected = upcoming_holidays(date.succ()).take(60).collect::<Vec<_>>();
+            for (i, holiday) in expected.into_iter().enumerate() {
+                assert_eq!(Some(holiday), nth_holiday_after(*date, i + 1), "{}", i);
+            }
+        }
+    }
+
+    #[test]
+    fn test_previous_n_holidays() {
+        assert_eq!(
+            vec![
+                (NaiveDate::from_ymd(2024, 4, 1), Holiday::AnnandagPask),
+                (NaiveDate::from_ymd(2024, 3, 31), Holiday::Paskdagen),
+                (NaiveDate::from_ymd(2024, 3, 29), Holiday::Langfredagen),
+            ],
+            previous_n_holidays(NaiveDate::from_ymd(2024, 4, 1), 3)
+        );
+
+        assert!(previous_n_holidays(NaiveDate::from_ymd(2024, 4, 1), 0).is_empty());
+        assert_eq!(
+            1,
+            previous_n_holidays(NaiveDate::from_ymd(2024, 4, 1), 1).len()
+        );
+    }
+
+    #[test]
+    fn test_previous_n_holidays_into_previous_year() {
+        // Trettondedag jul and Nyårsafton come before Annandag jul.
+        assert_eq!(
+            vec![
+                (NaiveDate::from_ymd(2024, 1, 6), Holiday::TrettondedagJul),
+                (NaiveDate::from_ymd(2024, 1, 1), Holiday::Nyarsdagen),
+                (NaiveDate::from_ymd(2023, 12, 31), Holiday::Nyarsafton),
+            ],
+            previous_n_holidays(NaiveDate::from_ymd(2024, 1, 10), 3)
+        );
+    }
+
+    #[test]
+    fn test_holiday_proximity() {
+        // Annandag påsk.
+        assert_eq!(
+            (Some(0), Some(0)),
+            holiday_proximity(NaiveDate::from_ymd(2020, 4, 13))
+        );
+
+        // The day after, Första maj is next.
+        assert_eq!(
+            (Some(-1), Some(17)),
+            holiday_proximity(NaiveDate::from_ymd(2020, 4, 14))
+        );
+
+        // Ordinary Sundays don't count, Midsommardagen and Alla helgons dag are closest.
+        assert_eq!(
+            (Some(-113), Some(20)),
+            holiday_proximity(NaiveDate::from_ymd(2020, 10, 11))
+        );
+    }
+
+    #[test]
+    fn test_holidays_on_weekday() {
+        assert_eq!(
+            vec![
+                (NaiveDate::from_ymd(2023, 12, 25), Holiday::Juldagen),
+                (NaiveDate::from_ymd(2028, 12, 25), Holiday::Juldagen),
+            ],
+            holidays_on_weekday(Holiday::Juldagen, Weekday::Mon, 2020..2030)
+        );
+
+        let national_days = holidays_on_weekday(Holiday::Nationaldagen, Weekday::Mon, 2000..2031);
+        assert_eq!(
+            vec![2005, 2011, 2016, 2022],
+            national_days
+                .iter()
+                .map(|(d, _)| d.year())
+                .collect::<Vec<_>>()
+        );
+
+        // Always a Friday.
+        assert!(holidays_on_weekday(Holiday::Langfredagen, Weekday::Thu, 1900..2100).is_empty());
+        assert_eq!(
+            200,
+            holidays_on_weekday(Holiday::Langfredagen, Weekday::Fri, 1900..2100).len()
+        );
+    }
+
+    #[test]
+    fn test_annotate_dates() {
+        let start = NaiveDate::from_ymd(2020, 4, 1);
+        let dates = (0..30)
+            .map(|i| (start + Duration::days(i), i))
+            .collect::<Vec<_>>();
+
+        let annotated = annotate_dates(&dates);
+        assert_eq!(30, annotated.len());
+
+        // Out of order and across years.
+        let scattered = vec![
+            (NaiveDate::from_ymd(2024, 12, 31), 'a'),
+            (NaiveDate::from_ymd(2020, 4, 10), 'b'),
+            (NaiveDate::from_ymd(2024, 12, 31), 'c'),
+            (NaiveDate::from_ymd(2021, 12, 31), 'd'),
+            (NaiveDate::from_ymd(2020, 1, 6), 'e'),
+        ];
+        assert_eq!(
+            scattered
+                .iter()
+                .map(|(date, c)| (*date, *c, day_kind(date), holiday_at(*date)))
+                .collect::<Vec<_>>(),
+            annotate_dates(&scattered)
+        );
+
+        for ((date, i), (a_date, a_i, kind, holiday)) in dates.iter().zip(annotated.iter()) {
+            assert_eq!((date, i), (a_date, a_i));
+            assert_eq!(day_kind(date), *kind);
+            assert_eq!(holiday_at(*date), *holiday);
+        }
+
+        // Skärtorsdagen through Annandag påsk.
+        let easter = annotated[8..13]
+            .iter()
+            .map(|(_, _, kind, holiday)| (*kind, *holiday))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (DayKind::DayBeforeHoliday, None),
+                (DayKind::Holiday, Some(Holiday::Langfredagen)),
+                (DayKind::DayBeforeHoliday, None),
+                (DayKind::Holiday, Some(Holiday::Paskdagen)),
+                (DayKind::Holiday, Some(Holiday::AnnandagPask)),
+            ],
+            easter
+        );
+    }
+
+    #[test]
+    fn test_group_dates_by_kind() {
+        let dates = vec![
+            (NaiveDate::from_ymd(2020, 4, 10), "Långfredagen"),
+            (NaiveDate::from_ymd(2020, 9, 14), "Monday"),
+            (NaiveDate::from_ymd(2020, 9, 20), "Sunday"),
+            (NaiveDate::from_ymd(2020, 9, 15), "Tuesday"),
+        ];
+
+        let groups = group_dates_by_kind(dates);
+        assert_eq!(3, groups.len());
+        assert_eq!(
+            vec!["Monday", "Tuesday"],
+            groups[&DayKind::Weekday]
+                .iter()
+                .map(|(_, t)| *t)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(2, groups[&DayKind::Holiday].len());
+        assert!(groups[&DayKind::DayBeforeHoliday].is_empty());
+
+        let empty = group_dates_by_kind(Vec::<(NaiveDate, ())>::new());
+        assert_eq!(3, empty.len());
+        assert!(empty.values().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_nearest_holiday() {
+        // Långfredagen.
+        let good_friday = NaiveDate::from_ymd(2020, 4, 10);
+        assert_eq!(
+            (good_friday, Holiday::Langfredagen, 0),
+            nearest_holiday(good_friday)
+        );
+
+        // Nyårsdagen is the day before, Trettondedag jul 4 days after.
+        assert_eq!(
+            (NaiveDate::from_ymd(2020, 1, 1), Holiday::Nyarsdagen, -1),
+            nearest_holiday(NaiveDate::from_ymd(2020, 1, 2))
+        );
+        assert_eq!(
+            (NaiveDate::from_ymd(2020, 1, 6), Holiday::TrettondedagJul, 1),
+            nearest_holiday(NaiveDate::from_ymd(2020, 1, 5))
+        );
+    }
+
+    #[test]
+    fn test_nearest_holiday_tie() {
+        // 10 days after Första maj and 10 days before Kristi himmelsfärdsdag.
+        assert_eq!(
+            (
+                NaiveDate::from_ymd(2020, 5, 21),
+                Holiday::KristiHimmelfardsdag,
+                10
+            ),
+            nearest_holiday(NaiveDate::from_ymd(2020, 5, 11))
+        );
+    }
+
+    #[test]
+    fn test_public_holiday_by_name() {
+        let easter = NaiveDate::from_ymd(2024, 3, 31);
+        assert_eq!(Some(easter), public_holiday_by_name("Påskdagen", 2024));
+        assert_eq!(Some(easter), public_holiday_by_name("easter sunday", 2024));
+
+        let ascension_day = NaiveDate::from_ymd(2024, 5, 9);
+        assert_eq!(
+            Some(ascension_day),
+            public_holiday_by_name("KRISTI HIMMELSFÄRDSDAG", 2024)
+        );
+        assert_eq!(
+            Some(ascension_day),
+            public_holiday_by_name("Ascension Day", 2024)
+        );
+
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2024, 6, 21)),
+            public_holiday_by_name("midsommarafton", 2024)
+        );
+        assert_eq!(None, public_holiday_by_name("Valborg", 2024));
+    }
+
+    #[test]
+    fn test_public_holiday_by_holiday() {
+        for holiday in holidays().filter(|h| h.is_holiday_in(2024)) {
+            let date = public_holiday_by_holiday(holiday, 2024);
+            assert_eq!(Some(holiday), holiday_at(date));
+            assert_eq!(
+                Some(date),
+                public_holiday_by_name(holiday.english_name(), 2024)
+            );
+        }
+    }
+
+    #[test]
+    fn test_holidays_in_year_are_chronological() {
+        for year in 1990..2050 {
+            let holidays = holidays_in_year(year).collect::<Vec<_>>();
+            assert_eq!(16, holidays.len());
+            // Some years two holidays coincide, like Kristi himmelsfärdsdag on Första maj 2008.
+            assert!(holidays.windows(2).all(|w| w[0].0 <= w[1].0), "{}", year);
+        }
+    }
+
+    #[test]
+    fn test_day_before_national_day_with_late_pingst() {
+        // Pingstdagen 2025 is the 8th of June, after Nationaldagen on Friday the 6th.
+        assert_eq!(
+            (NaiveDate::from_ymd(2025, 6, 6), Holiday::Nationaldagen),
+            next_upcoming_holiday(&NaiveDate::from_ymd(2025, 6, 2))
+        );
+        assert_eq!(
+            DayKind::DayBeforeHoliday,
+            day_kind(&NaiveDate::from_ymd(2025, 6, 5))
+        );
+    }
+
+    #[test]
+    fn test_annandag_pingst_until_2004() {
+        // Pingstdagen is the 30th of May 2004, and the 8th of June 2003.
+        assert_eq!(
+            Some(Holiday::AnnandagPingst),
+            holiday_at(NaiveDate::from_ymd(2004, 5, 31))
+        );
+        assert_eq!(DayKind::Holiday, day_kind(&NaiveDate::from_ymd(2003, 6, 9)));
+        assert_eq!(DayKind::Weekday, day_kind(&NaiveDate::from_ymd(2003, 6, 6)));
+        assert_eq!(None, public_holiday_by_name("Nationaldagen", 2003));
+
+        // Pingstdagen is the 19th of May 2024.
+        assert_eq!(
+            DayKind::Weekday,
+            day_kind(&NaiveDate::from_ymd(2024, 5, 20))
+        );
+        assert_eq!(None, public_holiday_by_name("Annandag pingst", 2024));
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2005, 6, 6)),
+            public_holiday_by_name("Nationaldagen", 2005)
+        );
+
+        assert_eq!(
+            Some((NaiveDate::from_ymd(2005, 1, 1), Holiday::Nyarsdagen)),
+            nth_holiday_after(NaiveDate::from_ymd(2003, 12, 31), 17)
+        );
+    }
+
+    #[test]
+    fn test_holiday_at_switching_years() {
+        for year in 1990..2050 {
+            for (date, holiday) in holidays_in_year(year) {
+                assert!(holiday_at(date).is_some(), "{} {}", holiday, date);
+                assert_eq!(None, holiday_at(NaiveDate::from_ymd(year, 3, 1)));
+            }
+        }
+    }
 }
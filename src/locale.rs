@@ -0,0 +1,3 @@
+//! Names of months and weekdays.
+
+pub mod swedish;
@@ -0,0 +1,124 @@
+//! Swedish month and weekday names, capitalized as at the start of a sentence.
+
+use chrono::Weekday;
+
+const MONTHS: [&str; 12] = [
+    "Januari",
+    "Februari",
+    "Mars",
+    "April",
+    "Maj",
+    "Juni",
+    "Juli",
+    "Augusti",
+    "September",
+    "Oktober",
+    "November",
+    "December",
+];
+
+const MONTH_ABBRS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dec",
+];
+
+/// Returns the name of month, "Januari" through "December".
+///
+/// Panics if month is not within 1-12.
+pub fn month_name_swedish(month: u32) -> &'static str {
+    MONTHS[month_index(month)]
+}
+
+/// Returns the three letter abbreviation of month, "Jan" through "Dec".
+///
+/// Panics if month is not within 1-12.
+pub fn month_abbr_swedish(month: u32) -> &'static str {
+    MONTH_ABBRS[month_index(month)]
+}
+
+/// Returns the name of weekday, "Måndag" through "Söndag".
+pub fn weekday_name_swedish(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Måndag",
+        Weekday::Tue => "Tisdag",
+        Weekday::Wed => "Onsdag",
+        Weekday::Thu => "Torsdag",
+        Weekday::Fri => "Fredag",
+        Weekday::Sat => "Lördag",
+        Weekday::Sun => "Söndag",
+    }
+}
+
+/// Returns the three letter abbreviation of weekday, "Mån" through "Sön".
+pub fn weekday_abbr_swedish(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Mån",
+        Weekday::Tue => "Tis",
+        Weekday::Wed => "Ons",
+        Weekday::Thu => "Tor",
+        Weekday::Fri => "Fre",
+        Weekday::Sat => "Lör",
+        Weekday::Sun => "Sön",
+    }
+}
+
+fn month_index(month: u32) -> usize {
+    assert!((1..=12).contains(&month), "Invalid month: {}", month);
+    month as usize - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const WEEKDAYS: [Weekday; 7] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+
+    #[test]
+    fn test_month_names() {
+        let names = (1..=12).map(month_name_swedish).collect::<HashSet<_>>();
+        let abbrs = (1..=12).map(month_abbr_swedish).collect::<HashSet<_>>();
+
+        assert_eq!(12, names.len());
+        assert_eq!(12, abbrs.len());
+        assert!(names.iter().all(|n| !n.is_empty()));
+        assert!(abbrs.iter().all(|a| a.chars().count() == 3));
+
+        assert_eq!("Januari", month_name_swedish(1));
+        assert_eq!("Okt", month_abbr_swedish(10));
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        let names = WEEKDAYS
+            .iter()
+            .map(|w| weekday_name_swedish(*w))
+            .collect::<HashSet<_>>();
+        let abbrs = WEEKDAYS
+            .iter()
+            .map(|w| weekday_abbr_swedish(*w))
+            .collect::<HashSet<_>>();
+
+        assert_eq!(7, names.len());
+        assert_eq!(7, abbrs.len());
+        assert!(names.iter().all(|n| !n.is_empty()));
+        assert!(abbrs.iter().all(|a| a.chars().count() == 3));
+
+        for weekday in WEEKDAYS.iter() {
+            assert!(weekday_name_swedish(*weekday).starts_with(weekday_abbr_swedish(*weekday)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_month() {
+        month_name_swedish(13);
+    }
+}
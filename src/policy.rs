@@ -0,0 +1,497 @@
+use crate::{holiday_at, DayKind, Eve, Holiday};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+use chrono_tz::Tz;
+
+/// How dates are classified into `DayKind`s.
+///
+/// The default, `DayKindPolicy::swedish`, classifies the same way as `day_kind`.
+/// Named holidays are always `DayKind::Holiday`, the policy decides how Saturdays, Sundays
+/// and the days before holidays are classified.
+///
+/// Accepted by `day_kind_with`, `slice_on_day_kind_with` and `DayKind::next_start_with`.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{day_kind, DayKind, DayKindPolicy};
+///
+/// let sunday = NaiveDate::from_ymd(2024, 3, 10);
+/// assert_eq!(DayKind::Holiday, day_kind(&sunday));
+///
+/// let policy = DayKindPolicy::swedish().with_weekends();
+/// assert_eq!(DayKind::Weekend, policy.day_kind(&sunday));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DayKindPolicy {
+    saturday: DayKind,
+    sunday: DayKind,
+    eves: bool,
+    half_days: bool,
+    de_facto_holidays: bool,
+    eve_cutoff: Option<NaiveTime>,
+}
+
+/// Why a day is classified as its `DayKind`, returned by `day_kind_with_reason`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reason {
+    /// The day is a named holiday.
+    Holiday(Holiday),
+    /// The kind of every Sunday, a holiday by default.
+    Sunday,
+    /// The day before a named holiday.
+    EveOf(Holiday),
+    /// The day before a Saturday or Sunday classified as a holiday, other than the
+    /// Saturday before a Sunday. Like a Friday with `DayKindPolicy::saturday(DayKind::Holiday)`.
+    EveOfWeekend,
+    /// The kind of every Saturday, a day before a holiday by default.
+    Saturday,
+    /// A shortened working day, see `DayKindPolicy::with_half_days`.
+    HalfDay(Eve),
+    /// None of the above, an ordinary weekday.
+    Weekday,
+}
+
+/// The eves many workplaces close at lunch on, when on a weekday.
+/// Julafton, Midsommarafton and Nyårsafton are left out since they're holidays here,
+/// unlike in `is_half_day_eve`.
+const SHORTENED_DAY_EVES: [Eve; 4] = [
+    Eve::Trettondagsafton,
+    Eve::Skartorsdagen,
+    Eve::Valborgsmassoafton,
+    Eve::Allhelgonaafton,
+];
+
+impl DayKindPolicy {
+    /// Saturdays are days before holidays and Sundays are holidays, same as `day_kind`.
+    pub fn swedish() -> Self {
+        Self {
+            saturday: DayKind::DayBeforeHoliday,
+            sunday: DayKind::Holiday,
+            eves: true,
+            half_days: false,
+            de_facto_holidays: true,
+            eve_cutoff: None,
+        }
+    }
+
+    /// Classifies ordinary Saturdays and Sundays as `DayKind::Weekend`, leaving
+    /// `DayKind::Holiday` to the named holidays.
+    ///
+    /// A Friday is then an ordinary weekday, unless followed by a holiday.
+    pub fn with_weekends(mut self) -> Self {
+        self.saturday = DayKind::Weekend;
+        self.sunday = DayKind::Weekend;
+        self
+    }
+
+    /// Sets the kind of an ordinary Saturday, `DayKind::DayBeforeHoliday` by default.
+    ///
+    /// A Saturday before a holiday is still at least a `DayKind::DayBeforeHoliday`,
+    /// unless `without_eves` is used.
+    pub fn saturday(mut self, kind: DayKind) -> Self {
+        self.saturday = kind;
+        self
+    }
+
+    /// Sets the kind of an ordinary Sunday, `DayKind::Holiday` by default.
+    ///
+    /// A Saturday is only a day before a holiday due to the Sunday when the Sunday is
+    /// a `DayKind::Holiday`.
+    pub fn sunday(mut self, kind: DayKind) -> Self {
+        self.sunday = kind;
+        self
+    }
+
+    /// Stops classifying days before holidays as `DayKind::DayBeforeHoliday`, they get the
+    /// kind of their weekday instead. Ordinary Saturdays are still `DayBeforeHoliday` unless
+    /// changed with `saturday`.
+    pub fn without_eves(mut self) -> Self {
+        self.eves = false;
+        self
+    }
+
+    /// Classifies Julafton, Midsommarafton and Nyårsafton as `DayKind::Holiday`, which is
+    /// the default. See `Holiday::is_de_facto`.
+    pub fn with_de_facto_holidays(mut self) -> Self {
+        self.de_facto_holidays = true;
+        self
+    }
+
+    /// Only the red days count as named holidays. Julafton, Midsommarafton and Nyårsafton
+    /// are then classified like any other day before a holiday.
+    pub fn without_de_facto_holidays(mut self) -> Self {
+        self.de_facto_holidays = false;
+        self
+    }
+
+    /// Classifies Trettondagsafton, Skärtorsdagen, Valborgsmässoafton and Allhelgonaafton
+    /// as `DayKind::HalfDay` when they fall on a Monday to Friday.
+    ///
+    /// Many workplaces close at lunch on these days, by custom or collective agreement.
+    /// Julafton, Midsommarafton and Nyårsafton are holidays of their own.
+    pub fn with_half_days(mut self) -> Self {
+        self.half_days = true;
+        self
+    }
+
+    /// Eves only start at time, local time of day, like 13:00 on Julafton in many
+    /// agreements. Before then, the days before holidays and the de facto holidays are
+    /// classified as if they weren't eves, usually as a `DayKind::Weekday`.
+    ///
+    /// Only affects the classifications with a time of day, `day_kind_at` and slicing.
+    /// ```
+    /// use chrono::{NaiveTime, TimeZone};
+    /// use chrono_tz::Europe::Stockholm;
+    /// use holidays_se::{DayKind, DayKindPolicy};
+    ///
+    /// let policy = DayKindPolicy::swedish().eve_cutoff(NaiveTime::from_hms(13, 0, 0));
+    /// let julafton = Stockholm.ymd(2024, 12, 24);
+    /// assert_eq!(DayKind::Weekday, policy.day_kind_at(&julafton.and_hms(12, 59, 0)));
+    /// assert_eq!(DayKind::Holiday, policy.day_kind_at(&julafton.and_hms(13, 0, 0)));
+    /// ```
+    pub fn eve_cutoff(mut self, time: NaiveTime) -> Self {
+        self.eve_cutoff = Some(time);
+        self
+    }
+
+    /// Returns the `DayKind` at dt under this policy, taking `eve_cutoff` into account.
+    pub fn day_kind_at(&self, dt: &DateTime<Tz>) -> DayKind {
+        let local = dt.naive_local();
+        match self.eve_cutoff {
+            Some(cutoff) if local.time() < cutoff => Self {
+                eves: false,
+                de_facto_holidays: false,
+                ..*self
+            }
+            .day_kind(&local.date()),
+            _ => self.day_kind(&local.date()),
+        }
+    }
+
+    /// Returns the first instant after dt the kind may change at, the next midnight or
+    /// the eve cutoff of the day.
+    pub(crate) fn next_boundary(&self, dt: &DateTime<Tz>) -> DateTime<Tz> {
+        let cutoff = self
+            .eve_cutoff
+            .filter(|cutoff| dt.naive_local().time() < *cutoff)
+            .and_then(|cutoff| dt.date().and_time(cutoff));
+
+        cutoff.unwrap_or_else(|| (dt.date() + Duration::days(1)).and_hms(0, 0, 0))
+    }
+
+    /// Returns the `DayKind` of d under this policy.
+    pub fn day_kind<D>(&self, d: &D) -> DayKind
+    where
+        D: Datelike,
+    {
+        self.day_kind_with_reason(d).0
+    }
+
+    /// Same as `day_kind`, along with the reason for the classification.
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use holidays_se::{DayKind, DayKindPolicy, Reason};
+    ///
+    /// let policy = DayKindPolicy::swedish().saturday(DayKind::Holiday);
+    /// assert_eq!(
+    ///     (DayKind::DayBeforeHoliday, Reason::EveOfWeekend),
+    ///     policy.day_kind_with_reason(&NaiveDate::from_ymd(2024, 3, 8))
+    /// );
+    /// ```
+    pub fn day_kind_with_reason<D>(&self, d: &D) -> (DayKind, Reason)
+    where
+        D: Datelike,
+    {
+        // Compare whole dates, ordinals don't carry over into the next year.
+        let date = NaiveDate::from_yo(d.year(), d.ordinal());
+        self.classify_by(date, |d| self.named_holiday(d))
+    }
+
+    /// Same as `day_kind_with_reason`, with the named holidays looked up by named_holiday.
+    /// The de facto holidays are already expected to be filtered out if not to be counted.
+    pub(crate) fn classify_by<F>(&self, date: NaiveDate, named_holiday: F) -> (DayKind, Reason)
+    where
+        F: Fn(NaiveDate) -> Option<Holiday>,
+    {
+        let kind = self.weekday_kind(date);
+        let reason = match date.weekday() {
+            Weekday::Sat => Reason::Saturday,
+            Weekday::Sun => Reason::Sunday,
+            _ => Reason::Weekday,
+        };
+
+        if let Some(holiday) = named_holiday(date) {
+            return (DayKind::Holiday, Reason::Holiday(holiday));
+        }
+        if kind == DayKind::Holiday {
+            return (kind, reason);
+        }
+
+        let tomorrow = date.succ();
+        let eve = match named_holiday(tomorrow) {
+            Some(holiday) => Some(Reason::EveOf(holiday)),
+            None if self.weekday_kind(tomorrow) == DayKind::Holiday => Some(Reason::EveOfWeekend),
+            None => None,
+        };
+
+        // An eve only replaces a weekday rule of the same kind when it names the holiday,
+        // like Påskafton being the eve of Påskdagen rather than a Saturday.
+        let (kind, reason) = match eve {
+            Some(eve) if self.eves && kind < DayKind::DayBeforeHoliday => {
+                (DayKind::DayBeforeHoliday, eve)
+            }
+            Some(eve @ Reason::EveOf(_)) if self.eves && kind == DayKind::DayBeforeHoliday => {
+                (kind, eve)
+            }
+            _ => (kind, reason),
+        };
+
+        match self.shortened_day_eve(date) {
+            Some(eve) if self.half_days && kind < DayKind::HalfDay => {
+                (DayKind::HalfDay, Reason::HalfDay(eve))
+            }
+            _ => (kind, reason),
+        }
+    }
+
+    fn named_holiday(&self, date: NaiveDate) -> Option<Holiday> {
+        holiday_at(date).filter(|h| self.de_facto_holidays || h.is_red_day())
+    }
+
+    /// Returns Trettondagsafton, Skärtorsdagen, Valborgsmässoafton and Allhelgonaafton
+    /// when falling on a Monday to Friday, the `SHORTENED_DAY_EVES`.
+    fn shortened_day_eve(&self, date: NaiveDate) -> Option<Eve> {
+        let weekend = date.weekday() == Weekday::Sat || date.weekday() == Weekday::Sun;
+        Eve::from_date(date).filter(|eve| !weekend && SHORTENED_DAY_EVES.contains(eve))
+    }
+
+    /// The kind of date by its weekday alone, disregarding holidays.
+    fn weekday_kind(&self, date: NaiveDate) -> DayKind {
+        match date.weekday() {
+            Weekday::Sat => self.saturday,
+            Weekday::Sun => self.sunday,
+            _ => DayKind::Weekday,
+        }
+    }
+}
+
+/// Same as `DayKindPolicy::swedish`.
+impl Default for DayKindPolicy {
+    fn default() -> Self {
+        Self::swedish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weekends() {
+        let policy = DayKindPolicy::swedish().with_weekends();
+        let d = |m, d| NaiveDate::from_ymd(2024, m, d);
+
+        // An ordinary weekend.
+        assert_eq!(DayKind::Weekday, policy.day_kind(&d(3, 8)));
+        assert_eq!(DayKind::Weekend, policy.day_kind(&d(3, 9)));
+        assert_eq!(DayKind::Weekend, policy.day_kind(&d(3, 10)));
+
+        // Easter, Påskafton is still a weekend day.
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind(&d(3, 28)));
+        assert_eq!(DayKind::Holiday, policy.day_kind(&d(3, 29)));
+        assert_eq!(DayKind::Weekend, policy.day_kind(&d(3, 30)));
+        assert_eq!(DayKind::Holiday, policy.day_kind(&d(3, 31)));
+
+        // Alla helgons dag on a Saturday.
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind(&d(11, 1)));
+        assert_eq!(DayKind::Holiday, policy.day_kind(&d(11, 2)));
+        assert_eq!(DayKind::Weekend, policy.day_kind(&d(11, 3)));
+    }
+
+    #[test]
+    fn test_saturdays_and_sundays() {
+        let d = |m, d| NaiveDate::from_ymd(2024, m, d);
+
+        // A six day week, only Sundays off.
+        let policy = DayKindPolicy::swedish().saturday(DayKind::Weekday);
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind(&d(3, 9)));
+
+        let policy = policy.without_eves();
+        assert_eq!(DayKind::Weekday, policy.day_kind(&d(3, 9)));
+        assert_eq!(DayKind::Holiday, policy.day_kind(&d(3, 10)));
+        // Skärtorsdagen and Påskafton.
+        assert_eq!(DayKind::Weekday, policy.day_kind(&d(3, 28)));
+        assert_eq!(DayKind::Weekday, policy.day_kind(&d(3, 30)));
+
+        // Both weekend days off, the Friday leads into them.
+        let policy = DayKindPolicy::swedish().saturday(DayKind::Holiday);
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind(&d(3, 8)));
+        assert_eq!(DayKind::Holiday, policy.day_kind(&d(3, 9)));
+
+        // Sundays as weekends, Saturdays are no longer before a holiday.
+        let policy = DayKindPolicy::swedish()
+            .saturday(DayKind::Weekday)
+            .sunday(DayKind::Weekend);
+        assert_eq!(DayKind::Weekday, policy.day_kind(&d(3, 9)));
+        assert_eq!(DayKind::Weekend, policy.day_kind(&d(3, 10)));
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind(&d(3, 30)));
+    }
+
+    #[test]
+    fn test_de_facto_holidays() {
+        let d = |m, d| NaiveDate::from_ymd(2024, m, d);
+        let eves = [d(6, 21), d(12, 24), d(12, 31)];
+
+        let policy = DayKindPolicy::swedish().with_de_facto_holidays();
+        assert_eq!(DayKindPolicy::swedish(), policy);
+        for date in &eves {
+            assert_eq!(DayKind::Holiday, policy.day_kind(date), "{}", date);
+        }
+
+        let policy = DayKindPolicy::swedish().without_de_facto_holidays();
+        for date in &eves {
+            assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind(date), "{}", date);
+        }
+        // Lillejulafton is no longer before a holiday, but Midsommardagen still is.
+        assert_eq!(DayKind::Weekday, policy.day_kind(&d(12, 23)));
+        assert_eq!(DayKind::Holiday, policy.day_kind(&d(6, 22)));
+    }
+
+    #[test]
+    fn test_eve_of_new_years_day() {
+        // Nyårsafton is only the day before Nyårsdagen, in the next year, without the
+        // de facto holidays. Leap years included, where it's the 366th day.
+        let policy = DayKindPolicy::swedish()
+            .without_de_facto_holidays()
+            .with_weekends();
+
+        for year in 2015..=2030 {
+            let nyarsafton = NaiveDate::from_ymd(year, 12, 31);
+            let expected =
+                if nyarsafton.weekday() == Weekday::Sat || nyarsafton.weekday() == Weekday::Sun {
+                    DayKind::Weekend
+                } else {
+                    DayKind::DayBeforeHoliday
+                };
+            assert_eq!(expected, policy.day_kind(&nyarsafton), "{}", nyarsafton);
+            assert_eq!(expected, policy.day_kind(&nyarsafton.and_hms(23, 59, 59)));
+            assert_eq!(
+                DayKind::Holiday,
+                policy.day_kind(&NaiveDate::from_ymd(year + 1, 1, 1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_eve_cutoff() {
+        use crate::slice_on_day_kind_with;
+        use chrono::TimeZone;
+        use chrono_tz::Europe::Stockholm;
+
+        let at = |d, h| Stockholm.ymd(2024, 12, d).and_hms(h, 0, 0);
+        let policy = DayKindPolicy::swedish().eve_cutoff(NaiveTime::from_hms(13, 0, 0));
+
+        assert_eq!(DayKind::Weekday, policy.day_kind_at(&at(23, 10)));
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind_at(&at(23, 14)));
+        // Holidays and Saturdays aren't eves.
+        assert_eq!(DayKind::Holiday, policy.day_kind_at(&at(25, 10)));
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind_at(&at(21, 10)));
+        // Without a time of day, the eve covers the whole day.
+        assert_eq!(DayKind::DayBeforeHoliday, policy.day_kind(&at(23, 10)));
+
+        let slices = slice_on_day_kind_with(at(23, 0)..at(25, 0), policy)
+            .map(|s| (s.range, s.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (at(23, 0)..at(23, 13), DayKind::Weekday),
+                (at(23, 13)..at(24, 0), DayKind::DayBeforeHoliday),
+                (at(24, 0)..at(24, 13), DayKind::Weekday),
+                (at(24, 13)..at(25, 0), DayKind::Holiday),
+            ],
+            slices
+        );
+    }
+
+    #[test]
+    fn test_half_days() {
+        let policy = DayKindPolicy::swedish().with_half_days();
+        let d = |m, d| NaiveDate::from_ymd(2024, m, d);
+
+        // Trettondagsafton is a Friday, the Saturday is Trettondedag jul.
+        assert_eq!(DayKind::DayBeforeHoliday, crate::day_kind(&d(1, 5)));
+        assert_eq!(DayKind::HalfDay, policy.day_kind(&d(1, 5)));
+        assert_eq!(DayKind::HalfDay, policy.day_kind(&d(3, 28)));
+        assert_eq!(DayKind::HalfDay, policy.day_kind(&d(4, 30)));
+        assert_eq!(DayKind::HalfDay, policy.day_kind(&d(11, 1)));
+
+        // Eves that are holidays or on a weekend are left alone.
+        assert_eq!(DayKind::Holiday, policy.day_kind(&d(6, 21)));
+        assert_eq!(DayKind::Weekday, policy.day_kind(&d(4, 29)));
+        let saturday_valborg = NaiveDate::from_ymd(2022, 4, 30);
+        assert_eq!(
+            DayKind::DayBeforeHoliday,
+            policy.day_kind(&saturday_valborg)
+        );
+
+        let both = DayKindPolicy::swedish().with_half_days().with_weekends();
+        assert_eq!(DayKind::HalfDay, both.day_kind(&d(4, 30)));
+        assert_eq!(DayKind::Weekend, both.day_kind(&saturday_valborg));
+    }
+
+    #[test]
+    fn test_weekends_only_replace_ordinary_weekend_days() {
+        let policy = DayKindPolicy::swedish().with_weekends();
+        let start = NaiveDate::from_ymd(2024, 1, 1);
+        let end = NaiveDate::from_ymd(2025, 1, 1);
+
+        for date in crate::dates_in(start..end) {
+            let default = crate::day_kind(&date);
+            let kind = policy.day_kind(&date);
+            match date.weekday() {
+                Weekday::Sat | Weekday::Sun if crate::holiday_at(date).is_none() => {
+                    assert_eq!(DayKind::Weekend, kind, "{}", date)
+                }
+                // A Friday is only a day before a holiday if the Saturday is a named one.
+                Weekday::Fri if kind == DayKind::Weekday => {
+                    assert_eq!(None, crate::holiday_at(date.succ()), "{}", date)
+                }
+                _ => assert_eq!(default, kind, "{}", date),
+            }
+        }
+    }
+
+    #[test]
+    fn test_reason_agrees_with_day_kind() {
+        let policies = vec![
+            DayKindPolicy::swedish(),
+            DayKindPolicy::swedish().with_weekends(),
+            DayKindPolicy::swedish().with_half_days(),
+            DayKindPolicy::swedish().without_eves(),
+            DayKindPolicy::swedish().saturday(DayKind::Holiday),
+            DayKindPolicy::swedish().without_de_facto_holidays(),
+        ];
+        let start = NaiveDate::from_ymd(2024, 1, 1);
+        let end = NaiveDate::from_ymd(2025, 1, 1);
+
+        for policy in policies {
+            for date in crate::dates_in(start..end) {
+                let (kind, reason) = policy.day_kind_with_reason(&date);
+                assert_eq!(crate::day_kind_with(&date, policy), kind, "{}", date);
+
+                let reason_fits = match reason {
+                    Reason::Holiday(_) => kind == DayKind::Holiday,
+                    Reason::EveOf(_) | Reason::EveOfWeekend => kind == DayKind::DayBeforeHoliday,
+                    Reason::HalfDay(_) => kind == DayKind::HalfDay,
+                    Reason::Saturday | Reason::Sunday => {
+                        date.weekday() == Weekday::Sat || date.weekday() == Weekday::Sun
+                    }
+                    Reason::Weekday => kind == DayKind::Weekday,
+                };
+                assert!(reason_fits, "{} {:?} {:?}", date, kind, reason);
+            }
+        }
+
+        for (date, kind) in crate::classify_days(start..end) {
+            assert_eq!(crate::day_kind_with_reason(&date).0, kind, "{}", date);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! The holidays in effect, which have changed over the years.
+
+use crate::{holidays, Holiday};
+
+/// The set of holidays in effect during a range of years, see `RuleSet::for_year`.
+///
+/// Only the changes since 1953 are modelled, when Midsommardagen and Alla helgons dag
+/// got their current, floating, dates. Earlier years are not supported.
+/// ```
+/// use holidays_se::{Holiday, RuleSet};
+///
+/// assert!(RuleSet::for_year(2004).contains(Holiday::AnnandagPingst));
+/// assert!(!RuleSet::for_year(2005).contains(Holiday::AnnandagPingst));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleSet {
+    /// 1953 through 2004. Annandag pingst is a holiday, Nationaldagen is not.
+    Before2005,
+    /// Since 2005, when Nationaldagen replaced Annandag pingst.
+    Since2005,
+}
+
+impl RuleSet {
+    /// Returns the rule set in effect during year.
+    pub fn for_year(year: i32) -> Self {
+        if year < 2005 {
+            Self::Before2005
+        } else {
+            Self::Since2005
+        }
+    }
+
+    /// Returns true if holiday is in effect under the rule set.
+    pub fn contains(self, holiday: Holiday) -> bool {
+        !matches!(
+            (self, holiday),
+            (Self::Before2005, Holiday::Nationaldagen) | (Self::Since2005, Holiday::AnnandagPingst)
+        )
+    }
+
+    /// The holidays in effect under the rule set, in the same order as `holidays()`.
+    pub fn holidays(self) -> impl Iterator<Item = Holiday> {
+        holidays().filter(move |h| self.contains(*h))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_sets() {
+        assert_eq!(RuleSet::Before2005, RuleSet::for_year(1953));
+        assert_eq!(RuleSet::Before2005, RuleSet::for_year(2004));
+        assert_eq!(RuleSet::Since2005, RuleSet::for_year(2005));
+
+        assert_eq!(16, RuleSet::Before2005.holidays().count());
+        assert_eq!(16, RuleSet::Since2005.holidays().count());
+        assert!(RuleSet::Since2005.contains(Holiday::Nationaldagen));
+        assert!(!RuleSet::Before2005.contains(Holiday::Nationaldagen));
+    }
+}
@@ -0,0 +1,408 @@
+use crate::{dates_in, is_working_day, DayKind};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Weekday};
+use chrono_tz::Tz;
+use std::{cmp, ops::Range};
+
+/// Working hours for each day of the week.
+/// Only applies to `DayKind::Weekday` days, days before holidays and holidays are off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    // Indexed by number of days from Monday.
+    hours: [Option<Range<NaiveTime>>; 7],
+}
+
+impl Schedule {
+    /// A schedule without any working hours at all.
+    pub fn empty() -> Self {
+        Self {
+            hours: Default::default(),
+        }
+    }
+
+    /// Sets the working hours of weekday.
+    pub fn with_hours(mut self, weekday: Weekday, hours: Range<NaiveTime>) -> Self {
+        self.hours[weekday.num_days_from_monday() as usize] = Some(hours);
+        self
+    }
+
+    /// Makes weekday a day off.
+    pub fn without_hours(mut self, weekday: Weekday) -> Self {
+        self.hours[weekday.num_days_from_monday() as usize] = None;
+        self
+    }
+
+    pub fn hours_on(&self, weekday: Weekday) -> Option<Range<NaiveTime>> {
+        self.hours[weekday.num_days_from_monday() as usize].clone()
+    }
+
+    /// The total working time of Monday through Friday, the only days that can be
+    /// `DayKind::Weekday`.
+    fn weekday_hours(&self) -> Duration {
+        self.hours[..5]
+            .iter()
+            .flatten()
+            .filter(|h| h.start < h.end)
+            .fold(Duration::zero(), |acc, h| acc + (h.end - h.start))
+    }
+
+    /// The working hours of date, in the local time of tz.
+    /// None if date has no hours, or if they start or end in a gap of local time,
+    /// like the switch to daylight saving time.
+    fn hours_at(&self, tz: &Tz, date: NaiveDate) -> Option<Range<DateTime<Tz>>> {
+        let hours = self.hours_on(date.weekday())?;
+        let at = |t: NaiveTime| tz.from_local_datetime(&date.and_time(t)).earliest();
+
+        Some(at(hours.start)?..at(hours.end)?)
+    }
+}
+
+/// The Swedish standard, Monday to Friday 09:00-17:00.
+impl Default for Schedule {
+    fn default() -> Self {
+        let nine_to_five = NaiveTime::from_hms(9, 0, 0)..NaiveTime::from_hms(17, 0, 0);
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]
+        .iter()
+        .fold(Self::empty(), |schedule, weekday| {
+            schedule.with_hours(*weekday, nine_to_five.clone())
+        })
+    }
+}
+
+/// Returns the point in time when hours_needed hours of working time has passed since from.
+/// Working time is the hours of schedule, on `DayKind::Weekday` days only.
+///
+/// Zero or negative hours returns from as is.
+///
+/// Returns None if schedule has no working hours Monday to Friday, since no amount of
+/// hours would ever be available, or if the hours aren't available before
+/// `NaiveDate::MAX`, the last date that can be represented.
+pub fn working_hours_available(
+    from: DateTime<Tz>,
+    hours_needed: f64,
+    schedule: &Schedule,
+) -> Option<DateTime<Tz>> {
+    let needed_ms = (hours_needed * 3_600_000.0).round();
+    if needed_ms.is_nan() || needed_ms <= 0.0 {
+        return Some(from);
+    }
+
+    // Not even every week filled with working days would do.
+    let mut date = from.naive_local().date();
+    let weeks_left = (NaiveDate::MAX - date).num_weeks() + 1;
+    let weekly_ms = schedule.weekday_hours().num_milliseconds();
+    if (weekly_ms as f64) * (weeks_left as f64) < needed_ms {
+        return None;
+    }
+
+    let mut remaining = Duration::milliseconds(needed_ms as i64);
+    let tz = from.timezone();
+    loop {
+        if let Some(hours) = Some(date)
+            .filter(|date| is_working_day(*date))
+            .and_then(|date| schedule.hours_at(&tz, date))
+        {
+            let start = cmp::max(hours.start, from);
+            if start < hours.end {
+                let available = hours.end - start;
+                if available >= remaining {
+                    return Some(start + remaining);
+                }
+                remaining -= available;
+            }
+        }
+
+        date = date.succ_opt()?;
+    }
+}
+
+/// Returns the point in time within range when threshold_hours of working time has passed
+/// since its start, or `None` if range ends before that.
+/// See `working_hours_available`.
+pub fn overtime_threshold_reached(
+    range: Range<DateTime<Tz>>,
+    threshold_hours: f64,
+    schedule: &Schedule,
+) -> Option<DateTime<Tz>> {
+    let threshold_ms = (threshold_hours * 3_600_000.0).round();
+    if threshold_ms.is_nan() || threshold_ms <= 0.0 {
+        return Some(range.start).filter(|start| *start <= range.end);
+    }
+
+    // Saturated, far more than the working time of any range anyway.
+    let mut remaining = Duration::milliseconds(threshold_ms.min(i64::MAX as f64) as i64);
+    for period in working_periods(range, schedule) {
+        let available = period.end - period.start;
+        if available >= remaining {
+            return Some(period.start + remaining);
+        }
+        remaining -= available;
+    }
+
+    None
+}
+
+/// The working hours of schedule within range, in chronological order.
+fn working_periods(
+    range: Range<DateTime<Tz>>,
+    schedule: &Schedule,
+) -> impl Iterator<Item = Range<DateTime<Tz>>> + '_ {
+    let tz = range.start.timezone();
+    let first = range.start.naive_local().date();
+    let last = range.end.naive_local().date();
+
+    dates_in(first..last.succ())
+        .filter(|date| is_working_day(*date))
+        .filter_map(move |date| schedule.hours_at(&tz, date))
+        .filter_map(move |hours| {
+            let start = cmp::max(hours.start, range.start);
+            let end = cmp::min(hours.end, range.end);
+            if start < end {
+                Some(start..end)
+            } else {
+                None
+            }
+        })
+}
+
+/// Returns the working time of schedule between start and end.
+/// Just like `working_hours_available`, only `DayKind::Weekday` days count.
+pub fn working_hours_in_range(
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    schedule: &Schedule,
+) -> Duration {
+    if end <= start {
+        return Duration::zero();
+    }
+
+    working_periods(start..end, schedule).fold(Duration::zero(), |acc, period| {
+        acc + (period.end - period.start)
+    })
+}
+
+/// Same as `working_hours_in_range`, in whole minutes.
+pub fn working_minutes_between(start: DateTime<Tz>, end: DateTime<Tz>, schedule: &Schedule) -> i64 {
+    working_hours_in_range(start, end, schedule).num_minutes()
+}
+
+/// Same as `working_hours_in_range`, in whole seconds.
+pub fn working_seconds_between(start: DateTime<Tz>, end: DateTime<Tz>, schedule: &Schedule) -> i64 {
+    working_hours_in_range(start, end, schedule).num_seconds()
+}
+
+/// Returns the number of whole minutes between start and end on `DayKind::Weekday` days,
+/// around the clock.
+pub fn calendar_working_minutes_between(start: DateTime<Tz>, end: DateTime<Tz>) -> i64 {
+    if end <= start {
+        return 0;
+    }
+
+    DayKind::Weekday.duration_in_range(start..end).num_minutes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::Europe::Stockholm;
+
+    #[test]
+    fn test_full_working_week() {
+        let monday = Stockholm.ymd(2020, 9, 14).and_hms(9, 0, 0);
+        assert_eq!(
+            Stockholm.ymd(2020, 9, 18).and_hms(17, 0, 0),
+            working_hours_available(monday, 40.0, &Schedule::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_starting_mid_day() {
+        // Friday afternoon, continues on Monday.
+        let friday = Stockholm.ymd(2020, 9, 18).and_hms(15, 30, 0);
+        assert_eq!(
+            Stockholm.ymd(2020, 9, 21).and_hms(10, 0, 0),
+            working_hours_available(friday, 2.5, &Schedule::default()).unwrap()
+        );
+
+        // Before working hours start.
+        let early = Stockholm.ymd(2020, 9, 14).and_hms(6, 0, 0);
+        assert_eq!(
+            Stockholm.ymd(2020, 9, 14).and_hms(10, 0, 0),
+            working_hours_available(early, 1.0, &Schedule::default()).unwrap()
+        );
+
+        assert_eq!(
+            Some(friday),
+            working_hours_available(friday, 0.0, &Schedule::default())
+        );
+    }
+
+    #[test]
+    fn test_skips_holidays_and_new_year() {
+        // Tuesday the 29th is the last working day of 2020.
+        let tuesday = Stockholm.ymd(2020, 12, 29).and_hms(9, 0, 0);
+        assert_eq!(
+            Stockholm.ymd(2021, 1, 4).and_hms(13, 0, 0),
+            working_hours_available(tuesday, 12.0, &Schedule::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_days_without_hours() {
+        let schedule = Schedule::default()
+            .without_hours(Weekday::Mon)
+            .without_hours(Weekday::Tue);
+        assert_eq!(None, schedule.hours_on(Weekday::Mon));
+
+        let monday = Stockholm.ymd(2020, 9, 14).and_hms(9, 0, 0);
+        assert_eq!(
+            Stockholm.ymd(2020, 9, 16).and_hms(13, 0, 0),
+            working_hours_available(monday, 4.0, &schedule).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_working_minutes_in_a_week() {
+        let schedule = Schedule::default().with_hours(
+            Weekday::Fri,
+            NaiveTime::from_hms(8, 0, 0)..NaiveTime::from_hms(16, 0, 0),
+        );
+        let monday = Stockholm.ymd(2020, 9, 14).and_hms(0, 0, 0);
+        let next_monday = Stockholm.ymd(2020, 9, 21).and_hms(0, 0, 0);
+
+        assert_eq!(
+            2400,
+            working_minutes_between(monday, next_monday, &schedule)
+        );
+        assert_eq!(
+            2400 * 60,
+            working_seconds_between(monday, next_monday, &schedule)
+        );
+        assert_eq!(
+            Duration::hours(40),
+            working_hours_in_range(monday, next_monday, &schedule)
+        );
+
+        // Partial days at both ends.
+        let tuesday_noon = Stockholm.ymd(2020, 9, 15).and_hms(12, 0, 0);
+        let wednesday_ten = Stockholm.ymd(2020, 9, 16).and_hms(10, 0, 0);
+        assert_eq!(
+            6 * 60,
+            working_minutes_between(tuesday_noon, wednesday_ten, &schedule)
+        );
+        assert_eq!(
+            0,
+            working_minutes_between(wednesday_ten, tuesday_noon, &schedule)
+        );
+    }
+
+    #[test]
+    fn test_working_minutes_over_easter() {
+        // Skärtorsdagen through Annandag påsk has no working hours at all.
+        let thursday = Stockholm.ymd(2020, 4, 9).and_hms(0, 0, 0);
+        let tuesday = Stockholm.ymd(2020, 4, 14).and_hms(0, 0, 0);
+        assert_eq!(
+            0,
+            working_minutes_between(thursday, tuesday, &Schedule::default())
+        );
+        assert_eq!(0, calendar_working_minutes_between(thursday, tuesday));
+    }
+
+    #[test]
+    fn test_calendar_working_minutes() {
+        let monday = Stockholm.ymd(2020, 9, 14).and_hms(0, 0, 0);
+        let next_monday = Stockholm.ymd(2020, 9, 21).and_hms(0, 0, 0);
+
+        // Saturdays are days before holidays, leaving Monday through Friday.
+        assert_eq!(
+            5 * 24 * 60,
+            calendar_working_minutes_between(monday, next_monday)
+        );
+    }
+
+    #[test]
+    fn test_overtime_threshold_reached() {
+        let monday = Stockholm.ymd(2020, 9, 14).and_hms(8, 0, 0);
+        let friday_evening = Stockholm.ymd(2020, 9, 18).and_hms(17, 0, 0);
+        let sunday = Stockholm.ymd(2020, 9, 20).and_hms(0, 0, 0);
+
+        assert_eq!(
+            Some(friday_evening),
+            overtime_threshold_reached(monday..sunday, 40.0, &Schedule::default())
+        );
+        assert_eq!(
+            Some(friday_evening),
+            overtime_threshold_reached(monday..friday_evening, 40.0, &Schedule::default())
+        );
+        assert_eq!(
+            None,
+            overtime_threshold_reached(monday..sunday, 40.5, &Schedule::default())
+        );
+
+        // Never looks past the end of range.
+        let tuesday = Stockholm.ymd(2020, 9, 15).and_hms(8, 0, 0);
+        for threshold in &[2e6, 1e9, f64::MAX] {
+            assert_eq!(
+                None,
+                overtime_threshold_reached(monday..tuesday, *threshold, &Schedule::default())
+            );
+        }
+        assert_eq!(
+            None,
+            overtime_threshold_reached(monday..tuesday, 1.0, &Schedule::empty())
+        );
+    }
+
+    #[test]
+    fn test_schedule_without_weekday_hours() {
+        let monday = Stockholm.ymd(2020, 9, 14).and_hms(9, 0, 0);
+        assert_eq!(
+            None,
+            working_hours_available(monday, 1.0, &Schedule::empty())
+        );
+
+        let weekends_only = Schedule::empty().with_hours(
+            Weekday::Sat,
+            NaiveTime::from_hms(9, 0, 0)..NaiveTime::from_hms(17, 0, 0),
+        );
+        assert_eq!(None, working_hours_available(monday, 1.0, &weekends_only));
+    }
+
+    #[test]
+    fn test_hours_in_dst_gap() {
+        // 02:15 doesn't exist on Sunday the 29th, the switch to summer time.
+        let schedule = Schedule::default().with_hours(
+            Weekday::Sun,
+            NaiveTime::from_hms(2, 15, 0)..NaiveTime::from_hms(5, 0, 0),
+        );
+        let monday = Stockholm.ymd(2020, 3, 23).and_hms(9, 0, 0);
+        assert_eq!(
+            Stockholm.ymd(2020, 4, 1).and_hms(13, 0, 0),
+            working_hours_available(monday, 60.0, &schedule).unwrap()
+        );
+
+        let next_monday = Stockholm.ymd(2020, 3, 30).and_hms(0, 0, 0);
+        assert_eq!(
+            Duration::hours(40),
+            working_hours_in_range(monday, next_monday, &schedule)
+        );
+    }
+
+    #[test]
+    fn test_more_hours_than_representable() {
+        let monday = Stockholm.ymd(2020, 9, 14).and_hms(9, 0, 0);
+        assert_eq!(
+            None,
+            working_hours_available(monday, 1e9, &Schedule::default())
+        );
+        assert_eq!(
+            None,
+            working_hours_available(monday, f64::MAX, &Schedule::default())
+        );
+    }
+}
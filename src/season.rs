@@ -0,0 +1,110 @@
+//! Holidays grouped into the seasons people plan their time off around.
+
+use crate::{maundy_thursday, Eve, Holiday};
+use chrono::NaiveDate;
+use std::ops::Range;
+
+/// A group of related holidays, together with their eves.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::HolidaySeason;
+///
+/// assert_eq!(
+///     NaiveDate::from_ymd(2024, 12, 24)..NaiveDate::from_ymd(2024, 12, 27),
+///     HolidaySeason::Christmas.range_in(2024)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidaySeason {
+    /// Skärtorsdagen through Annandag påsk.
+    Easter,
+    /// Midsommarafton and Midsommardagen.
+    Midsummer,
+    /// Julafton through Annandag jul.
+    Christmas,
+    /// Nyårsafton and Nyårsdagen, ending in the following year.
+    NewYear,
+}
+
+impl HolidaySeason {
+    /// Every season, in the order they occur during a year.
+    pub const ALL: [HolidaySeason; 4] = [
+        Self::Easter,
+        Self::Midsummer,
+        Self::Christmas,
+        Self::NewYear,
+    ];
+
+    /// The holidays of the season, in chronological order.
+    pub fn holidays(self) -> &'static [Holiday] {
+        match self {
+            Self::Easter => &[
+                Holiday::Langfredagen,
+                Holiday::Paskdagen,
+                Holiday::AnnandagPask,
+            ],
+            Self::Midsummer => &[Holiday::Midsommarafton, Holiday::Midsommardagen],
+            Self::Christmas => &[Holiday::Julafton, Holiday::Juldagen, Holiday::AnnandagJul],
+            Self::NewYear => &[Holiday::Nyarsafton, Holiday::Nyarsdagen],
+        }
+    }
+
+    /// Returns the dates of the season starting in year, from its first eve up until,
+    /// but not including, the day after its last holiday.
+    ///
+    /// The range of `NewYear` ends in the year after year.
+    pub fn range_in(self, year: i32) -> Range<NaiveDate> {
+        match self {
+            Self::Easter => maundy_thursday(year)..Holiday::AnnandagPask.date_in(year).succ(),
+            Self::Midsummer => {
+                Eve::Midsommarafton.date_in(year)..Holiday::Midsommardagen.date_in(year).succ()
+            }
+            Self::Christmas => {
+                Eve::Julafton.date_in(year)..Holiday::AnnandagJul.date_in(year).succ()
+            }
+            Self::NewYear => {
+                Eve::Nyarsafton.date_in(year)..Holiday::Nyarsdagen.date_in(year + 1).succ()
+            }
+        }
+    }
+}
+
+/// Returns every season starting in year with its dates, in chronological order.
+pub fn seasons_in_year(year: i32) -> Vec<(HolidaySeason, Range<NaiveDate>)> {
+    HolidaySeason::ALL
+        .iter()
+        .map(|season| (*season, season.range_in(year)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dates_in, holiday_at};
+
+    #[test]
+    fn test_seasons_2024() {
+        let d = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(
+            vec![
+                (HolidaySeason::Easter, d(2024, 3, 28)..d(2024, 4, 2)),
+                (HolidaySeason::Midsummer, d(2024, 6, 21)..d(2024, 6, 23)),
+                (HolidaySeason::Christmas, d(2024, 12, 24)..d(2024, 12, 27)),
+                (HolidaySeason::NewYear, d(2024, 12, 31)..d(2025, 1, 2)),
+            ],
+            seasons_in_year(2024)
+        );
+    }
+
+    #[test]
+    fn test_season_holidays_within_range() {
+        for year in 2018..=2030 {
+            for season in HolidaySeason::ALL.iter() {
+                let named = dates_in(season.range_in(year))
+                    .filter_map(holiday_at)
+                    .collect::<Vec<_>>();
+                assert_eq!(season.holidays().to_vec(), named, "{:?} {}", season, year);
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! The years the holiday rules of this crate are valid for.
+
+use crate::{day_kind, next_upcoming_holiday, DayKind, Holiday};
+use chrono::{Datelike, NaiveDate};
+use std::{error, fmt, ops::RangeInclusive};
+
+/// The years the holiday rules are valid for.
+///
+/// Midsommardagen and Alla helgons dag got their current, floating, dates in 1953 and
+/// the changes since are modelled by `RuleSet`. Earlier years are computed with the rules
+/// of 1953 anyway by the infallible functions of this crate, which is historically wrong.
+/// Use the `try_` functions to guard against that.
+pub const SUPPORTED_YEARS: RangeInclusive<i32> = 1953..=9999;
+
+/// Returns true if year is within `SUPPORTED_YEARS`.
+pub fn is_supported_year(year: i32) -> bool {
+    SUPPORTED_YEARS.contains(&year)
+}
+
+/// Same as `day_kind`, but fails for dates outside of `SUPPORTED_YEARS`.
+/// ```
+/// use chrono::NaiveDate;
+/// use holidays_se::{try_day_kind, DayKind, UnsupportedYear};
+///
+/// assert_eq!(Ok(DayKind::Holiday), try_day_kind(&NaiveDate::from_ymd(2024, 6, 6)));
+/// assert_eq!(Err(UnsupportedYear(1950)), try_day_kind(&NaiveDate::from_ymd(1950, 6, 6)));
+/// ```
+pub fn try_day_kind<D>(d: &D) -> Result<DayKind, UnsupportedYear>
+where
+    D: Datelike,
+{
+    check_year(d.year())?;
+    Ok(day_kind(d))
+}
+
+/// Same as `next_upcoming_holiday`, but fails for dates outside of `SUPPORTED_YEARS`.
+pub fn try_next_upcoming_holiday<D>(date: &D) -> Result<(NaiveDate, Holiday), UnsupportedYear>
+where
+    D: Datelike,
+{
+    check_year(date.year())?;
+    Ok(next_upcoming_holiday(date))
+}
+
+fn check_year(year: i32) -> Result<(), UnsupportedYear> {
+    if is_supported_year(year) {
+        Ok(())
+    } else {
+        Err(UnsupportedYear(year))
+    }
+}
+
+/// The year is outside of `SUPPORTED_YEARS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedYear(pub i32);
+
+impl fmt::Display for UnsupportedYear {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the holiday rules are not valid for {}, only {} to {}",
+            self.0,
+            SUPPORTED_YEARS.start(),
+            SUPPORTED_YEARS.end()
+        )
+    }
+}
+
+impl error::Error for UnsupportedYear {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supported_years() {
+        let date = NaiveDate::from_ymd(1953, 1, 1);
+        assert_eq!(Ok(DayKind::Holiday), try_day_kind(&date));
+        assert_eq!(
+            Ok((date, Holiday::Nyarsdagen)),
+            try_next_upcoming_holiday(&date)
+        );
+
+        let date = NaiveDate::from_ymd(1952, 12, 31);
+        assert_eq!(Err(UnsupportedYear(1952)), try_day_kind(&date));
+        assert_eq!(Err(UnsupportedYear(1952)), try_next_upcoming_holiday(&date));
+
+        assert!(is_supported_year(9999));
+        assert!(!is_supported_year(10000));
+    }
+}
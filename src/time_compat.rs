@@ -0,0 +1,35 @@
+//! Interoperability with the `time` crate, enabled by the `time-compat` feature.
+//!
+//! Adds `HasDayKind` for `time::Date`, along with free functions for the conversions.
+
+use crate::{day_kind, day_kind::sealed, next_upcoming_holiday, DayKind, HasDayKind, Holiday};
+use chrono::{Datelike, NaiveDate};
+
+/// Returns the `DayKind` of a `time::Date`.
+pub fn time_date_to_day_kind(d: time::Date) -> DayKind {
+    day_kind(&to_naive(d))
+}
+
+impl sealed::Sealed for time::Date {}
+
+/// Same as `time_date_to_day_kind`.
+impl HasDayKind for time::Date {
+    fn day_kind(&self) -> DayKind {
+        time_date_to_day_kind(*self)
+    }
+}
+
+/// Same as `next_upcoming_holiday`, for a `time::Date`.
+pub fn next_holiday_time(d: time::Date) -> (time::Date, Holiday) {
+    let (date, holiday) = next_upcoming_holiday(&to_naive(d));
+    (from_naive(date), holiday)
+}
+
+fn to_naive(d: time::Date) -> NaiveDate {
+    NaiveDate::from_yo(d.year(), d.ordinal().into())
+}
+
+fn from_naive(d: NaiveDate) -> time::Date {
+    time::Date::from_ordinal_date(d.year(), d.ordinal() as u16)
+        .expect("Date is out of range for the time crate")
+}
@@ -0,0 +1,259 @@
+use crate::{dates_in, is_sunday, month_range};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Weekday};
+use std::{cmp, ops::Range};
+
+/// Returns every Sunday of the year, in order.
+pub fn all_sundays_in_year(year: i32) -> Vec<NaiveDate> {
+    dates_in(NaiveDate::from_ymd(year, 1, 1)..NaiveDate::from_ymd(year + 1, 1, 1))
+        .filter(|d| is_sunday(*d))
+        .collect()
+}
+
+/// Returns every Sunday of the month, in order.
+///
+/// Panics if month is not within 1-12.
+pub fn all_sundays_in_month(year: i32, month: u32) -> Vec<NaiveDate> {
+    dates_in(month_range(year, month))
+        .filter(|d| is_sunday(*d))
+        .collect()
+}
+
+/// Returns the nth weekday of the month. Positive n counts from the start of the month,
+/// 1 being the first, and negative n from the end, -1 being the last.
+///
+/// `None` if n is 0 or the month has fewer than n such weekdays.
+/// Panics if month is not within 1-12.
+pub fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: i32) -> Option<NaiveDate> {
+    let matching = dates_in(month_range(year, month))
+        .filter(|d| d.weekday() == weekday)
+        .collect::<Vec<_>>();
+
+    let index = match n {
+        0 => return None,
+        n if n > 0 => n as usize - 1,
+        n => matching.len().checked_sub(n.unsigned_abs() as usize)?,
+    };
+
+    matching.get(index).copied()
+}
+
+/// Returns the signed number of days from a to b, positive if b is after a.
+pub fn days_between(a: NaiveDate, b: NaiveDate) -> i64 {
+    (b - a).num_days()
+}
+
+/// Returns the number of dates in the half-open range, 0 if it's empty or reversed.
+pub fn calendar_days_in_range(range: Range<NaiveDate>) -> u64 {
+    cmp::max(days_between(range.start, range.end), 0) as u64
+}
+
+/// Returns the ISO week date year and week number of date.
+///
+/// The ISO week year is not always the calendar year. Weeks start on Mondays, and the
+/// first week of a year is the one containing its first Thursday. So the last days of
+/// December can belong to week 1 of the next year, and the first days of January to the
+/// last week, 52 or 53, of the previous year.
+pub fn iso_week_for_date(date: NaiveDate) -> (i32, u32) {
+    let week = date.iso_week();
+    (week.year(), week.week())
+}
+
+/// Returns the duration of the overlap of the half-open ranges a and b.
+/// Ranges that only touch, like 08-12 and 12-16, don't overlap.
+pub fn overlapping_range_duration<T>(
+    a: Range<DateTime<T>>,
+    b: Range<DateTime<T>>,
+) -> Option<Duration>
+where
+    T: TimeZone,
+{
+    let start = cmp::max(a.start, b.start);
+    let end = cmp::min(a.end, b.end);
+    let overlap = end - start;
+
+    if overlap > Duration::zero() {
+        Some(overlap)
+    } else {
+        None
+    }
+}
+
+/// Returns true if the half-open ranges a and b share any instant.
+pub fn ranges_overlap<T>(a: Range<DateTime<T>>, b: Range<DateTime<T>>) -> bool
+where
+    T: TimeZone,
+{
+    overlapping_range_duration(a, b).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day_kind, DayKind, Holiday};
+    use chrono_tz::Europe::Stockholm;
+
+    #[test]
+    fn test_days_between() {
+        let d = NaiveDate::from_ymd;
+
+        assert_eq!(0, days_between(d(2020, 9, 14), d(2020, 9, 14)));
+        assert_eq!(6, days_between(d(2020, 9, 14), d(2020, 9, 20)));
+        assert_eq!(-6, days_between(d(2020, 9, 20), d(2020, 9, 14)));
+        assert_eq!(366, days_between(d(2020, 1, 1), d(2021, 1, 1)));
+        assert_eq!(2, days_between(d(2020, 12, 31), d(2021, 1, 2)));
+    }
+
+    #[test]
+    fn test_calendar_days_in_range() {
+        let d = NaiveDate::from_ymd;
+
+        assert_eq!(0, calendar_days_in_range(d(2020, 9, 14)..d(2020, 9, 14)));
+        assert_eq!(0, calendar_days_in_range(d(2020, 9, 20)..d(2020, 9, 14)));
+        assert_eq!(366, calendar_days_in_range(d(2020, 1, 1)..d(2021, 1, 1)));
+        assert_eq!(365, calendar_days_in_range(d(2021, 1, 1)..d(2022, 1, 1)));
+        assert_eq!(
+            dates_in(d(2020, 12, 20)..d(2021, 1, 10)).count() as u64,
+            calendar_days_in_range(d(2020, 12, 20)..d(2021, 1, 10))
+        );
+    }
+
+    #[test]
+    fn test_iso_week_around_new_year() {
+        // 2020 started on a Wednesday and was a leap year, giving it 53 weeks.
+        assert_eq!(
+            (2020, 53),
+            iso_week_for_date(NaiveDate::from_ymd(2020, 12, 29))
+        );
+        assert_eq!(
+            (2020, 53),
+            iso_week_for_date(NaiveDate::from_ymd(2020, 12, 31))
+        );
+        assert_eq!(
+            (2020, 53),
+            iso_week_for_date(NaiveDate::from_ymd(2021, 1, 1))
+        );
+        assert_eq!(
+            (2021, 1),
+            iso_week_for_date(NaiveDate::from_ymd(2021, 1, 4))
+        );
+
+        // The other way around, Monday the 30th is in the first week of 2025.
+        assert_eq!(
+            (2025, 1),
+            iso_week_for_date(NaiveDate::from_ymd(2024, 12, 30))
+        );
+    }
+
+    #[test]
+    fn test_first_sunday_of_each_month() {
+        for month in 1..=12 {
+            let sunday = nth_weekday_of_month(2020, month, Weekday::Sun, 1).unwrap();
+            assert_eq!(Some(&sunday), all_sundays_in_month(2020, month).first());
+            assert!(sunday.day() <= 7);
+        }
+    }
+
+    #[test]
+    fn test_last_saturday_of_october() {
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2024, 10, 26)),
+            nth_weekday_of_month(2024, 10, Weekday::Sat, -1)
+        );
+
+        // Alla helgons dag is the first Saturday from the 31st, which only
+        // is the last Saturday of October when the 31st is a Saturday.
+        let last = nth_weekday_of_month(2020, 10, Weekday::Sat, -1).unwrap();
+        assert_eq!(Holiday::AllaHelgonsDag.date_in(2020), last);
+        assert_ne!(
+            Holiday::AllaHelgonsDag.date_in(2024),
+            nth_weekday_of_month(2024, 10, Weekday::Sat, -1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_nth_weekday_out_of_range() {
+        assert_eq!(None, nth_weekday_of_month(2020, 6, Weekday::Mon, 0));
+        // June 2020 has four Saturdays.
+        assert!(nth_weekday_of_month(2020, 6, Weekday::Sat, 4).is_some());
+        assert_eq!(None, nth_weekday_of_month(2020, 6, Weekday::Sat, 5));
+        assert_eq!(
+            nth_weekday_of_month(2020, 6, Weekday::Sat, 1),
+            nth_weekday_of_month(2020, 6, Weekday::Sat, -4)
+        );
+        assert_eq!(None, nth_weekday_of_month(2020, 6, Weekday::Sat, -5));
+    }
+
+    #[test]
+    fn test_overlapping_range_duration() {
+        let at = |h, m, s| Stockholm.ymd(2020, 9, 17).and_hms(h, m, s);
+
+        // Partial overlap, in both orders.
+        assert_eq!(
+            Some(Duration::hours(2)),
+            overlapping_range_duration(at(8, 0, 0)..at(12, 0, 0), at(10, 0, 0)..at(16, 0, 0))
+        );
+        assert_eq!(
+            Some(Duration::hours(2)),
+            overlapping_range_duration(at(10, 0, 0)..at(16, 0, 0), at(8, 0, 0)..at(12, 0, 0))
+        );
+
+        // Full containment.
+        assert_eq!(
+            Some(Duration::hours(1)),
+            overlapping_range_duration(at(8, 0, 0)..at(16, 0, 0), at(9, 0, 0)..at(10, 0, 0))
+        );
+
+        // Sharing a single second.
+        assert_eq!(
+            Some(Duration::seconds(1)),
+            overlapping_range_duration(at(8, 0, 0)..at(12, 0, 1), at(12, 0, 0)..at(16, 0, 0))
+        );
+        assert!(ranges_overlap(
+            at(8, 0, 0)..at(12, 0, 1),
+            at(12, 0, 0)..at(16, 0, 0)
+        ));
+    }
+
+    #[test]
+    fn test_non_overlapping_ranges() {
+        let at = |h| Stockholm.ymd(2020, 9, 17).and_hms(h, 0, 0);
+
+        // Adjacent.
+        assert_eq!(
+            None,
+            overlapping_range_duration(at(8)..at(12), at(12)..at(16))
+        );
+        assert!(!ranges_overlap(at(8)..at(12), at(12)..at(16)));
+
+        // Disjoint.
+        assert!(!ranges_overlap(at(8)..at(10), at(12)..at(16)));
+
+        // An empty range overlaps nothing, not even a range containing it.
+        assert!(!ranges_overlap(at(8)..at(16), at(10)..at(10)));
+    }
+
+    #[test]
+    fn test_all_sundays_are_holidays() {
+        for year in 2000..=2030 {
+            for sunday in all_sundays_in_year(year) {
+                assert_eq!(DayKind::Holiday, day_kind(&sunday), "{}", sunday);
+            }
+        }
+    }
+
+    #[test]
+    fn test_number_of_sundays() {
+        assert_eq!(52, all_sundays_in_year(2020).len());
+        // Starts on a Sunday.
+        assert_eq!(53, all_sundays_in_year(2017).len());
+
+        let april = all_sundays_in_month(2020, 4);
+        assert_eq!(
+            vec![5, 12, 19, 26],
+            april.iter().map(|d| d.day()).collect::<Vec<_>>()
+        );
+
+        let months: usize = (1..=12).map(|m| all_sundays_in_month(2020, m).len()).sum();
+        assert_eq!(52, months);
+    }
+}
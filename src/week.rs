@@ -0,0 +1,69 @@
+use crate::{day_kind, holiday_at, DayKind, Holiday};
+use chrono::{Duration, NaiveDate, Weekday};
+
+/// Returns the named holiday of each day in the ISO week, Monday first.
+/// Ordinary Sundays are `None`, just like any other day without a name.
+///
+/// Panics if week does not exist in year.
+pub fn holiday_schedule_for_week(year: i32, week: u32) -> [Option<Holiday>; 7] {
+    let mut schedule = [None; 7];
+    for (i, date) in days_of_week(year, week).enumerate() {
+        schedule[i] = holiday_at(date);
+    }
+    schedule
+}
+
+/// Returns the `DayKind` of each day in the ISO week, Monday first.
+///
+/// Panics if week does not exist in year.
+pub fn day_kind_schedule_for_week(year: i32, week: u32) -> [DayKind; 7] {
+    let mut schedule = [DayKind::Weekday; 7];
+    for (i, date) in days_of_week(year, week).enumerate() {
+        schedule[i] = day_kind(&date);
+    }
+    schedule
+}
+
+fn days_of_week(year: i32, week: u32) -> impl Iterator<Item = NaiveDate> {
+    let monday = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)
+        .unwrap_or_else(|| panic!("Invalid week: {} {}", year, week));
+    (0..7).map(move |i| monday + Duration::days(i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_easter_week_2020() {
+        let holidays = holiday_schedule_for_week(2020, 15);
+        let kinds = day_kind_schedule_for_week(2020, 15);
+
+        // Skärtorsdagen has no name of its own.
+        assert_eq!(None, holidays[3]);
+        assert_eq!(DayKind::DayBeforeHoliday, kinds[3]);
+
+        assert_eq!(Some(Holiday::Langfredagen), holidays[4]);
+        assert_eq!(DayKind::Holiday, kinds[4]);
+
+        // The Sunday is Påskdagen.
+        assert_eq!(Some(Holiday::Paskdagen), holidays[6]);
+    }
+
+    #[test]
+    fn test_ordinary_sunday() {
+        // Week after Easter, starting with Annandag påsk.
+        let holidays = holiday_schedule_for_week(2020, 16);
+        let kinds = day_kind_schedule_for_week(2020, 16);
+
+        assert_eq!(Some(Holiday::AnnandagPask), holidays[0]);
+        assert_eq!(DayKind::Holiday, kinds[6]);
+        assert_eq!(None, holidays[6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_week() {
+        holiday_schedule_for_week(2020, 54);
+    }
+}
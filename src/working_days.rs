@@ -0,0 +1,279 @@
+use crate::{dates_in, day_kind, month_range, DayKind};
+use chrono::{Datelike, NaiveDate};
+
+/// Returns true if date is an ordinary working day, a `DayKind::Weekday`.
+pub fn is_working_day(date: NaiveDate) -> bool {
+    day_kind(&date) == DayKind::Weekday
+}
+
+/// Returns the number of `DayKind::Weekday` days from `from` up until, but not including, `to`.
+pub fn working_days_between(from: NaiveDate, to: NaiveDate) -> u32 {
+    dates_in(from..to)
+        .filter(|d| day_kind(d) == DayKind::Weekday)
+        .count() as u32
+}
+
+/// Returns the number of working days in the month.
+///
+/// Panics if month is not within 1-12.
+pub fn working_days_in_month(year: i32, month: u32) -> u32 {
+    let range = month_range(year, month);
+    working_days_between(range.start, range.end)
+}
+
+/// Returns the number of working days in each of the three months of the quarter.
+///
+/// Panics if quarter is not within 1-4.
+pub fn working_days_in_quarter(year: i32, quarter: u8) -> [u32; 3] {
+    assert!((1..=4).contains(&quarter), "Invalid quarter: {}", quarter);
+
+    let first_month = u32::from(quarter - 1) * 3 + 1;
+    [
+        working_days_in_month(year, first_month),
+        working_days_in_month(year, first_month + 1),
+        working_days_in_month(year, first_month + 2),
+    ]
+}
+
+/// Returns the number of working days in the quarter.
+///
+/// Panics if quarter is not within 1-4.
+pub fn quarter_working_days(year: i32, quarter: u8) -> u32 {
+    working_days_in_quarter(year, quarter).iter().sum()
+}
+
+/// Returns the nth working day of the month, counting from 1.
+/// `None` if n is 0 or the month has fewer working days than that.
+///
+/// Panics if month is not within 1-12.
+pub fn nth_working_day_of_month(year: i32, month: u32, n: u32) -> Option<NaiveDate> {
+    if n == 0 {
+        return None;
+    }
+
+    dates_in(month_range(year, month))
+        .filter(|d| day_kind(d) == DayKind::Weekday)
+        .nth(n as usize - 1)
+}
+
+/// Returns the last working day of the month.
+///
+/// Panics if month is not within 1-12.
+pub fn last_working_day_of_month(year: i32, month: u32) -> NaiveDate {
+    dates_in(month_range(year, month))
+        .filter(|d| day_kind(d) == DayKind::Weekday)
+        .last()
+        .expect("Every month has working days")
+}
+
+/// Returns the position of date among the working days of its year, counting from 1.
+/// `None` if date is not a working day.
+pub fn working_day_index_in_year(date: NaiveDate) -> Option<u32> {
+    if day_kind(&date) != DayKind::Weekday {
+        return None;
+    }
+
+    Some(working_days_between(NaiveDate::from_ymd(date.year(), 1, 1), date) + 1)
+}
+
+/// Returns the working day at index of the year, counting from 1.
+/// The inverse of `working_day_index_in_year`.
+pub fn working_day_from_index(year: i32, index: u32) -> Option<NaiveDate> {
+    if index == 0 {
+        return None;
+    }
+
+    dates_in(NaiveDate::from_ymd(year, 1, 1)..NaiveDate::from_ymd(year + 1, 1, 1))
+        .filter(|d| day_kind(d) == DayKind::Weekday)
+        .nth(index as usize - 1)
+}
+
+/// Returns the first working day of the year.
+/// Never the 1st of January, since Nyårsdagen is a holiday.
+pub fn first_working_day_of_year(year: i32) -> NaiveDate {
+    nth_working_day_of_month(year, 1, 1).expect("Every month has working days")
+}
+
+/// Returns the last working day of the year.
+/// At the latest the 29th of December, since Nyårsafton is a holiday.
+pub fn last_working_day_of_year(year: i32) -> NaiveDate {
+    last_working_day_of_month(year, 12)
+}
+
+pub fn working_days_in_year(year: i32) -> u32 {
+    working_days_between(
+        NaiveDate::from_ymd(year, 1, 1),
+        NaiveDate::from_ymd(year + 1, 1, 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_working_days_between() {
+        // Monday to Monday.
+        assert_eq!(
+            5,
+            working_days_between(
+                NaiveDate::from_ymd(2020, 9, 14),
+                NaiveDate::from_ymd(2020, 9, 21)
+            )
+        );
+
+        // Week before Easter, only Monday to Wednesday.
+        assert_eq!(
+            3,
+            working_days_between(
+                NaiveDate::from_ymd(2020, 4, 6),
+                NaiveDate::from_ymd(2020, 4, 13)
+            )
+        );
+
+        assert_eq!(
+            0,
+            working_days_between(
+                NaiveDate::from_ymd(2020, 4, 6),
+                NaiveDate::from_ymd(2020, 4, 1)
+            )
+        );
+    }
+
+    #[test]
+    fn test_working_days_in_months_2020() {
+        let expected = [21, 20, 22, 18, 18, 19, 23, 21, 22, 21, 21, 18];
+        for (i, days) in expected.iter().enumerate() {
+            assert_eq!(
+                *days,
+                working_days_in_month(2020, i as u32 + 1),
+                "Month {}",
+                i + 1
+            );
+        }
+
+        assert_eq!([21, 20, 22], working_days_in_quarter(2020, 1));
+        assert_eq!([21, 21, 18], working_days_in_quarter(2020, 4));
+
+        let quarters: u32 = (1..=4).map(|q| quarter_working_days(2020, q)).sum();
+        let months: u32 = (1..=12).map(|m| working_days_in_month(2020, m)).sum();
+        assert_eq!(244, working_days_in_year(2020));
+        assert_eq!(working_days_in_year(2020), quarters);
+        assert_eq!(working_days_in_year(2020), months);
+    }
+
+    #[test]
+    fn test_nth_working_day_of_month() {
+        // April 2020 starts on a Wednesday.
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2020, 4, 1)),
+            nth_working_day_of_month(2020, 4, 1)
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2020, 4, 7)),
+            nth_working_day_of_month(2020, 4, 5)
+        );
+        assert_eq!(None, nth_working_day_of_month(2020, 4, 0));
+
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2020, 2, 28)),
+            nth_working_day_of_month(2020, 2, 20)
+        );
+        assert_eq!(None, nth_working_day_of_month(2020, 2, 21));
+        assert_eq!(None, nth_working_day_of_month(2020, 2, 25));
+    }
+
+    #[test]
+    fn test_last_working_day_of_month() {
+        assert_eq!(
+            NaiveDate::from_ymd(2020, 2, 28),
+            last_working_day_of_month(2020, 2)
+        );
+        // Nyårsafton is a holiday, making the 30th a day before a holiday.
+        assert_eq!(
+            NaiveDate::from_ymd(2020, 12, 29),
+            last_working_day_of_month(2020, 12)
+        );
+        assert_eq!(
+            nth_working_day_of_month(2020, 12, working_days_in_month(2020, 12)),
+            Some(last_working_day_of_month(2020, 12))
+        );
+    }
+
+    #[test]
+    fn test_working_day_index_in_year() {
+        // Nyårsdagen is a Monday.
+        assert_eq!(
+            None,
+            working_day_index_in_year(NaiveDate::from_ymd(2024, 1, 1))
+        );
+        assert_eq!(
+            Some(1),
+            working_day_index_in_year(NaiveDate::from_ymd(2024, 1, 2))
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd(2024, 1, 2)),
+            working_day_from_index(2024, 1)
+        );
+
+        assert_eq!(None, working_day_from_index(2024, 0));
+        assert_eq!(
+            None,
+            working_day_from_index(2024, working_days_in_year(2024) + 1)
+        );
+    }
+
+    #[test]
+    fn test_working_day_index_round_trip() {
+        let start = NaiveDate::from_ymd(2024, 1, 1);
+        let end = NaiveDate::from_ymd(2025, 1, 1);
+
+        let mut expected = 1;
+        for date in dates_in(start..end) {
+            if let Some(index) = working_day_index_in_year(date) {
+                assert_eq!(expected, index);
+                assert_eq!(Some(date), working_day_from_index(2024, index));
+                expected += 1;
+            }
+        }
+        assert_eq!(working_days_in_year(2024) + 1, expected);
+    }
+
+    #[test]
+    fn test_first_and_last_working_day_of_year() {
+        // Nyårsdagen is a Friday, followed by a weekend.
+        assert_eq!(
+            NaiveDate::from_ymd(2021, 1, 4),
+            first_working_day_of_year(2021)
+        );
+        // Nyårsdagen is a Wednesday.
+        assert_eq!(
+            NaiveDate::from_ymd(2020, 1, 2),
+            first_working_day_of_year(2020)
+        );
+
+        // Nyårsafton is a Thursday, making the Wednesday a day before a holiday.
+        assert_eq!(
+            NaiveDate::from_ymd(2020, 12, 29),
+            last_working_day_of_year(2020)
+        );
+        assert!(!is_working_day(NaiveDate::from_ymd(2020, 12, 30)));
+        assert!(!is_working_day(NaiveDate::from_ymd(2020, 12, 31)));
+
+        for year in 2018..=2030 {
+            let first = first_working_day_of_year(year);
+            let last = last_working_day_of_year(year);
+            assert!(is_working_day(first) && is_working_day(last));
+            assert_eq!(
+                working_days_in_year(year),
+                working_days_between(first, last.succ())
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_quarter() {
+        working_days_in_quarter(2020, 5);
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use chrono::TimeZone;
 use chrono_tz::Europe::Stockholm;
 
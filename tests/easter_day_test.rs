@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use chrono::TimeZone;
 use chrono_tz::Europe::Stockholm;
 use holidays_se::easter_day_for_year;
@@ -232,3 +234,46 @@ fn the_great_easter_test() {
     assert_eq!(Stockholm.ymd(2098, 4, 20), easter_day_for_year(2098));
     assert_eq!(Stockholm.ymd(2099, 4, 12), easter_day_for_year(2099));
 }
+
+#[test]
+fn easter_relative_days() {
+    use chrono::NaiveDate;
+    use holidays_se::{day_kind, easter_monday, good_friday, maundy_thursday, DayKind};
+
+    assert_eq!(NaiveDate::from_ymd(2020, 4, 9), maundy_thursday(2020));
+    assert_eq!(NaiveDate::from_ymd(2020, 4, 10), good_friday(2020));
+    assert_eq!(NaiveDate::from_ymd(2020, 4, 13), easter_monday(2020));
+
+    assert_eq!(NaiveDate::from_ymd(2024, 3, 28), maundy_thursday(2024));
+    assert_eq!(NaiveDate::from_ymd(2024, 3, 29), good_friday(2024));
+    assert_eq!(NaiveDate::from_ymd(2024, 4, 1), easter_monday(2024));
+
+    for year in &[2020, 2024] {
+        assert_eq!(DayKind::DayBeforeHoliday, day_kind(&maundy_thursday(*year)));
+        assert_eq!(DayKind::Holiday, day_kind(&good_friday(*year)));
+        assert_eq!(DayKind::Holiday, day_kind(&easter_monday(*year)));
+    }
+}
+
+#[test]
+fn easter_sunday_extremes() {
+    use chrono::{Datelike, NaiveDate, Weekday};
+    use holidays_se::easter_sunday;
+
+    // The earliest and latest possible dates.
+    assert_eq!(NaiveDate::from_ymd(1818, 3, 22), easter_sunday(1818));
+    assert_eq!(NaiveDate::from_ymd(2285, 3, 22), easter_sunday(2285));
+    assert_eq!(NaiveDate::from_ymd(1943, 4, 25), easter_sunday(1943));
+    assert_eq!(NaiveDate::from_ymd(2038, 4, 25), easter_sunday(2038));
+
+    for year in 1753..3000 {
+        let easter = easter_sunday(year);
+        assert_eq!(Weekday::Sun, easter.weekday(), "{}", year);
+        assert!(
+            NaiveDate::from_ymd(year, 3, 22) <= easter
+                && easter <= NaiveDate::from_ymd(year, 4, 25),
+            "{}",
+            easter
+        );
+    }
+}
@@ -1,5 +1,6 @@
-use chrono::TimeZone;
-use chrono_tz::Europe::Stockholm;
+#![allow(deprecated)]
+
+use chrono::NaiveDate;
 
 #[test]
 fn test_iterator_2020() {
@@ -8,64 +9,67 @@ fn test_iterator_2020() {
     let mut iter = holidays_se::holidays_in_year(2020);
 
     assert_eq!(
-        (Nyarsdagen, Stockholm.ymd(2020, 1, 1)),
+        (NaiveDate::from_ymd(2020, 1, 1), Nyarsdagen),
+        iter.next().unwrap()
+    );
+    assert_eq!(
+        (NaiveDate::from_ymd(2020, 1, 6), TrettondedagJul),
         iter.next().unwrap()
     );
     assert_eq!(
-        (TrettondedagJul, Stockholm.ymd(2020, 1, 6)),
+        (NaiveDate::from_ymd(2020, 4, 10), Langfredagen),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Langfredagen, Stockholm.ymd(2020, 4, 10)),
+        (NaiveDate::from_ymd(2020, 4, 12), Paskdagen),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Paskdagen, Stockholm.ymd(2020, 4, 12)),
+        (NaiveDate::from_ymd(2020, 4, 13), AnnandagPask),
         iter.next().unwrap()
     );
     assert_eq!(
-        (AnnandagPask, Stockholm.ymd(2020, 4, 13)),
+        (NaiveDate::from_ymd(2020, 5, 1), ForstaMaj),
         iter.next().unwrap()
     );
-    assert_eq!((ForstaMaj, Stockholm.ymd(2020, 5, 1)), iter.next().unwrap());
     assert_eq!(
-        (KristiHimmelfardsdag, Stockholm.ymd(2020, 5, 21)),
+        (NaiveDate::from_ymd(2020, 5, 21), KristiHimmelfardsdag),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Pingstdagen, Stockholm.ymd(2020, 5, 31)),
+        (NaiveDate::from_ymd(2020, 5, 31), Pingstdagen),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Nationaldagen, Stockholm.ymd(2020, 6, 6)),
+        (NaiveDate::from_ymd(2020, 6, 6), Nationaldagen),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Midsommarafton, Stockholm.ymd(2020, 6, 19)),
+        (NaiveDate::from_ymd(2020, 6, 19), Midsommarafton),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Midsommardagen, Stockholm.ymd(2020, 6, 20)),
+        (NaiveDate::from_ymd(2020, 6, 20), Midsommardagen),
         iter.next().unwrap()
     );
     assert_eq!(
-        (AllaHelgonsDag, Stockholm.ymd(2020, 10, 31)),
+        (NaiveDate::from_ymd(2020, 10, 31), AllaHelgonsDag),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Julafton, Stockholm.ymd(2020, 12, 24)),
+        (NaiveDate::from_ymd(2020, 12, 24), Julafton),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Juldagen, Stockholm.ymd(2020, 12, 25)),
+        (NaiveDate::from_ymd(2020, 12, 25), Juldagen),
         iter.next().unwrap()
     );
     assert_eq!(
-        (AnnandagJul, Stockholm.ymd(2020, 12, 26)),
+        (NaiveDate::from_ymd(2020, 12, 26), AnnandagJul),
         iter.next().unwrap()
     );
     assert_eq!(
-        (Nyarsafton, Stockholm.ymd(2020, 12, 31)),
+        (NaiveDate::from_ymd(2020, 12, 31), Nyarsafton),
         iter.next().unwrap()
     );
 }
@@ -0,0 +1,37 @@
+#![cfg(feature = "time-compat")]
+
+use holidays_se::{next_holiday_time, time_date_to_day_kind, DayKind, HasDayKind, Holiday};
+use time::{Date, Month};
+
+fn date(year: i32, month: Month, day: u8) -> Date {
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+#[test]
+fn test_time_date_day_kind() {
+    assert_eq!(
+        DayKind::DayBeforeHoliday,
+        time_date_to_day_kind(date(2020, Month::April, 9))
+    );
+    assert_eq!(
+        DayKind::Holiday,
+        time_date_to_day_kind(date(2020, Month::April, 10))
+    );
+    assert_eq!(
+        DayKind::Weekday,
+        time_date_to_day_kind(date(2020, Month::April, 14))
+    );
+    assert_eq!(DayKind::Holiday, date(2020, Month::April, 10).day_kind());
+}
+
+#[test]
+fn test_next_holiday_time() {
+    assert_eq!(
+        (date(2020, Month::June, 6), Holiday::Nationaldagen),
+        next_holiday_time(date(2020, Month::June, 5))
+    );
+    assert_eq!(
+        (date(2020, Month::December, 31), Holiday::Nyarsafton),
+        next_holiday_time(date(2020, Month::December, 31))
+    );
+}